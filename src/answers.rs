@@ -0,0 +1,157 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs::{read_to_string, write},
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Known answers, stored as `<day> <part> <answer>` lines in the input folder.
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Answers {
+    pub fn path(folder: &Path) -> PathBuf {
+        folder.join("answers")
+    }
+
+    /// Loads the answers file from the input folder, a missing file is treated as empty
+    pub fn load(folder: &Path) -> io::Result<Answers> {
+        match read_to_string(Answers::path(folder)) {
+            Ok(content) => Answers::parse(&content),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, folder: &Path) -> io::Result<()> {
+        write(Answers::path(folder), self.to_string())
+    }
+
+    pub fn parse(content: &str) -> io::Result<Answers> {
+        let mut answers = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().and_then(|day| day.parse::<u8>().ok());
+            let part = fields.next().and_then(|part| part.parse::<u8>().ok());
+            let answer = fields.next().map(str::trim);
+
+            match (day, part, answer) {
+                (Some(day), Some(part), Some(answer)) if !answer.is_empty() => {
+                    answers.insert((day, part), answer.to_owned());
+                }
+                _ => {
+                    return Err(io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!("malformed answer in line {}: {}", index + 1, line),
+                    ))
+                }
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, answer: String) {
+        self.0.insert((day, part), answer);
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_owned()),
+            None => Verdict::Missing,
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ((day, part), answer) in self.0.iter() {
+            writeln!(f, "{:0>2} {} {}", day, part, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+/// Solves the day with the real input and compares it against the answers file.
+/// Skipped if the input or the answer is not available.
+#[cfg(test)]
+pub(crate) fn assert_answer(day: u8, part: u8) {
+    use crate::{
+        find_day,
+        utils::{input_folder, read_input, Part},
+    };
+
+    let Ok(input) = read_input(day, Part::Part1) else {
+        eprintln!("skipping day {} part {}: no input file", day, part);
+        return;
+    };
+
+    let answers = Answers::load(&input_folder()).expect("unable to read answers file");
+    let Some(expected) = answers.get(day, part) else {
+        eprintln!("skipping day {} part {}: no stored answer", day, part);
+        return;
+    };
+
+    let solution = find_day(day).expect("day is not registered");
+    assert_eq!(solution.solve(&input, part).unwrap(), expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, Verdict};
+
+    #[test]
+    fn parse_and_print() {
+        let answers = Answers::parse("# day part answer\n01 1 142\n1 2 281\n\n05 1 35\n").unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("142"));
+        assert_eq!(answers.get(1, 2), Some("281"));
+        assert_eq!(answers.to_string(), "01 1 142\n01 2 281\n05 1 35\n");
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("01 1 142\n").unwrap();
+
+        assert_eq!(answers.check(1, 1, "142"), Verdict::Pass);
+        assert_eq!(answers.check(1, 1, "143"), Verdict::Fail(String::from("142")));
+        assert_eq!(answers.check(1, 2, "281"), Verdict::Missing);
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(Answers::parse("01 x 142\n").is_err());
+        assert!(Answers::parse("01 1\n").is_err());
+    }
+}
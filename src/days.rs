@@ -14,4 +14,94 @@ pub mod day13;
 pub mod day14;
 pub mod day15;
 pub mod day16;
-pub mod day17;
\ No newline at end of file
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solution::Day;
+
+/// All implemented days, ordered by their day number
+pub static DAYS: &[&dyn Day] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn find_day(day: u8) -> Option<&'static dyn Day> {
+    DAYS.iter().find(|d| d.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DAYS;
+
+    #[test]
+    fn registry_is_ordered() {
+        assert!(DAYS.windows(2).all(|w| w[0].day() < w[1].day()));
+    }
+
+    /// Parts without an example that can be run as is: the day 20 examples have no `rx`
+    /// module, the day 21 examples use other step counts than part 2 and the day 24 example
+    /// uses a smaller test area than part 1. Their tests use smaller variants instead.
+    const WITHOUT_EXAMPLE: &[(u8, u8)] = &[(20, 2), (21, 2), (24, 1)];
+
+    #[test]
+    fn examples_are_solved() {
+        for solution in DAYS {
+            for part in 1..=solution.parts() {
+                let has_example = solution.examples().iter().any(|e| e.part == part);
+                let exempt = WITHOUT_EXAMPLE.contains(&(solution.day(), part));
+
+                assert!(
+                    has_example != exempt,
+                    "Day {} Part {} {}",
+                    solution.day(),
+                    part,
+                    if exempt {
+                        "is exempt but has an example"
+                    } else {
+                        "has no example"
+                    }
+                );
+            }
+
+            for example in solution.examples() {
+                assert_eq!(
+                    solution.solve(example.input, example.part).unwrap(),
+                    example.answer,
+                    "Day {} Part {}",
+                    solution.day(),
+                    example.part
+                );
+            }
+        }
+    }
+}
@@ -1,29 +1,14 @@
-use std::str::Lines;
-
-pub fn solve1(input: Lines) -> u32 {
-    input
-        .map(|line| {
-            let first = line
-                .chars()
-                .find(|c| c.is_numeric())
-                .expect("can't find first number")
-                .to_digit(10)
-                .expect("first number not in base 10");
-
-            let last = line
-                .chars()
-                .rev()
-                .find(|c| c.is_numeric())
-                .expect("can't find last number")
-                .to_digit(10)
-                .expect("last number not in base 10");
-
-            first * 10 + last
-        })
-        .sum()
-}
+use std::{cmp::Reverse, error::Error, fmt, str::Lines, sync::OnceLock};
+
+use aho_corasick::{AhoCorasick, BuildError, Match};
 
-const NUMBERS: [(&'static str, u32); 9] = [
+use crate::{
+    error::{AocError, Location},
+    solution::{Example, Solution},
+};
+
+/// Spelled out digits as they appear in the puzzle
+pub const ENGLISH: &[(&str, u32)] = &[
     ("one", 1),
     ("two", 2),
     ("three", 3),
@@ -35,70 +20,234 @@ const NUMBERS: [(&'static str, u32); 9] = [
     ("nine", 9),
 ];
 
-pub fn solve2(input: Lines) -> u32 {
-    input
-        .map(|line| {
-            let mut out: &str = line.into();
-            let mut first: Option<u32> = None;
-            let mut last: Option<u32> = None;
-
-            while first.is_none() {
-                let first_char = out.chars().next().unwrap();
-
-                if first_char.is_numeric() {
-                    first = Some(first_char.to_digit(10).unwrap());
-                    break;
-                }
-
-                for (needle, value) in NUMBERS.iter() {
-                    if out.starts_with(needle) {
-                        first = Some(value.clone());
-                        break;
-                    }
-                }
-
-                out = &out[1..];
+/// Inputs are ASCII only, so the umlaut is transliterated
+pub const GERMAN: &[(&str, u32)] = &[
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fuenf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+pub const ROMAN: &[(&str, u32)] = &[
+    ("I", 1),
+    ("II", 2),
+    ("III", 3),
+    ("IV", 4),
+    ("V", 5),
+    ("VI", 6),
+    ("VII", 7),
+    ("VIII", 8),
+    ("IX", 9),
+];
+
+/// The automaton matches the plain digits first, followed by the words
+const DIGITS: usize = 10;
+
+/// A digit found in a line, `offset` is the byte offset of its first character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    pub value: u32,
+    pub offset: usize,
+    /// Whether the digit was written as a word of the vocabulary
+    pub spelled: bool,
+}
+
+/// First and last digit of a single line, `line` starts at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub line: usize,
+    pub first: Digit,
+    pub last: Digit,
+}
+
+impl Calibration {
+    /// Combines the first and last digit into a two digit number
+    pub fn value(&self) -> u32 {
+        self.first.value * 10 + self.last.value
+    }
+}
+
+/// A line without any digit of the vocabulary, `line` starts at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDigit {
+    pub line: usize,
+    pub content: String,
+}
+
+impl fmt::Display for MissingDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no digit in line {}: {:?}", self.line, self.content)
+    }
+}
+
+impl Error for MissingDigit {}
+
+/// A vocabulary the [`CalibrationDecoder`] can not be built from
+#[derive(Debug, Clone)]
+pub enum VocabularyError {
+    /// An empty word would match between any two characters
+    EmptyWord {
+        value: u32,
+    },
+    /// Words stand for a single digit, `0` to `9`
+    NotADigit {
+        word: String,
+        value: u32,
+    },
+    TooLarge(BuildError),
+}
+
+impl fmt::Display for VocabularyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VocabularyError::EmptyWord { value } => write!(f, "empty word for the digit {}", value),
+            VocabularyError::NotADigit { word, value } => {
+                write!(f, "{:?} stands for {}, which is not a digit", word, value)
             }
+            VocabularyError::TooLarge(error) => write!(f, "vocabulary is too large: {}", error),
+        }
+    }
+}
+
+impl Error for VocabularyError {}
+
+/// Finds the first and last digit of a line, either written as a digit or as a word of
+/// its vocabulary.
+pub struct CalibrationDecoder {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+}
+
+impl CalibrationDecoder {
+    /// The digits `0` to `9` are always part of the vocabulary, words must not be empty and
+    /// stand for one of these digits
+    pub fn new(words: &[(&str, u32)]) -> Result<CalibrationDecoder, VocabularyError> {
+        if let Some(&(_, value)) = words.iter().find(|(word, _)| word.is_empty()) {
+            return Err(VocabularyError::EmptyWord { value });
+        }
+        if let Some(&(word, value)) = words.iter().find(|&&(_, value)| value >= DIGITS as u32) {
+            return Err(VocabularyError::NotADigit {
+                word: word.to_owned(),
+                value,
+            });
+        }
 
-            while last.is_none() {
-                let last_char = out.chars().next_back().unwrap();
+        let digits =
+            (0..DIGITS as u32).map(|digit| (char::from(b'0' + digit as u8).to_string(), digit));
+        let (patterns, values): (Vec<String>, Vec<u32>) = digits
+            .chain(words.iter().map(|&(word, value)| (word.to_owned(), value)))
+            .unzip();
 
-                if last_char.is_numeric() {
-                    last = Some(last_char.to_digit(10).unwrap());
-                    break;
-                }
+        Ok(CalibrationDecoder {
+            automaton: AhoCorasick::new(patterns).map_err(VocabularyError::TooLarge)?,
+            values,
+        })
+    }
+
+    /// First and last digit of `line`, words may overlap like in `eightwo`.
+    ///
+    /// The first digit is the match starting first and the last one the match ending last,
+    /// ties go to the longest word.
+    pub fn decode(&self, line: &str) -> Option<(Digit, Digit)> {
+        let mut first: Option<Match> = None;
+        let mut last: Option<Match> = None;
 
-                for (needle, value) in NUMBERS.iter() {
-                    if out.ends_with(needle) {
-                        last = Some(value.clone());
-                        break;
-                    }
-                }
+        for current in self.automaton.find_overlapping_iter(line) {
+            match first {
+                Some(first)
+                    if (first.start(), Reverse(first.len()))
+                        <= (current.start(), Reverse(current.len())) => {}
+                _ => first = Some(current),
+            }
 
-                out = &out[0..out.len() - 1];
+            match last {
+                Some(last) if (last.end(), last.len()) >= (current.end(), current.len()) => {}
+                _ => last = Some(current),
             }
+        }
+
+        Some((self.digit(first?), self.digit(last?)))
+    }
 
-            first.unwrap() * 10 + last.unwrap()
+    /// Decodes every line, lines without a digit are reported as errors
+    pub fn calibrations<'a>(
+        &'a self,
+        input: Lines<'a>,
+    ) -> impl Iterator<Item = Result<Calibration, MissingDigit>> + 'a {
+        input.enumerate().map(|(index, line)| {
+            let (first, last) = self.decode(line).ok_or_else(|| MissingDigit {
+                line: index + 1,
+                content: line.to_owned(),
+            })?;
+
+            Ok(Calibration {
+                line: index + 1,
+                first,
+                last,
+            })
         })
-        .sum()
+    }
+
+    fn digit(&self, found: Match) -> Digit {
+        let pattern = found.pattern().as_usize();
+
+        Digit {
+            value: self.values[pattern],
+            offset: found.start(),
+            spelled: pattern >= DIGITS,
+        }
+    }
 }
 
-#[inline(always)]
-pub fn parse_input(input: &str) -> Lines {
-    input.lines()
+pub fn solve1(input: Lines) -> Result<u32, MissingDigit> {
+    static DECODER: OnceLock<CalibrationDecoder> = OnceLock::new();
+    let decoder = DECODER.get_or_init(|| CalibrationDecoder::new(&[]).unwrap());
+
+    sum_calibration_values(input, decoder)
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::day01::{parse_input, solve1, solve2};
+pub fn solve2(input: Lines) -> Result<u32, MissingDigit> {
+    static DECODER: OnceLock<CalibrationDecoder> = OnceLock::new();
+    let decoder = DECODER.get_or_init(|| CalibrationDecoder::new(ENGLISH).unwrap());
 
-    const EXAMPLE_INPUT_1: &'static str = r#"1abc2
+    sum_calibration_values(input, decoder)
+}
+
+/// Sum of the calibration values of all lines, fails on the first line without a digit
+pub fn sum_calibration_values<'a>(
+    input: Lines<'a>,
+    decoder: &'a CalibrationDecoder,
+) -> Result<u32, MissingDigit> {
+    decoder
+        .calibrations(input)
+        .map(|calibration| calibration.map(|calibration| calibration.value()))
+        .sum()
+}
+
+/// Fails on the first byte that is not ASCII
+pub fn parse_input(input: &str) -> Result<Lines<'_>, AocError> {
+    match input.bytes().position(|byte| !byte.is_ascii()) {
+        None => Ok(input.lines()),
+        Some(offset) => Err(AocError::Parse {
+            day: Day01::DAY,
+            location: Location::new(input, &input[offset..]),
+            message: String::from("non-ASCII character"),
+        }),
+    }
+}
+
+pub const EXAMPLE_INPUT_1: &str = r#"1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet
 "#;
 
-    const EXAMPLE_INPUT_2: &'static str = r#"two1nine
+pub const EXAMPLE_INPUT_2: &str = r#"two1nine
 eightwothree
 abcone2threexyz
 xtwone3four
@@ -107,13 +256,156 @@ zoneight234
 7pqrstsixteen
 "#;
 
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT_1, "142"),
+        Example::new(2, EXAMPLE_INPUT_2, "281"),
+    ];
+
+    type Input<'s> = Lines<'s>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve1(input).map_err(|error| AocError::solve(Self::DAY, 1, error))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve2(input).map_err(|error| AocError::solve(Self::DAY, 2, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day01::{
+            parse_input, solve1, solve2, sum_calibration_values, Calibration, CalibrationDecoder,
+            Day01, Digit, MissingDigit, VocabularyError, ENGLISH, EXAMPLE_INPUT_1, EXAMPLE_INPUT_2,
+            GERMAN, ROMAN,
+        },
+        solution::Solution,
+    };
+
+    #[test]
+    fn decoder() {
+        let values = |decoder: &CalibrationDecoder, line| {
+            decoder
+                .decode(line)
+                .map(|(first, last)| (first.value, last.value))
+        };
+
+        let english = CalibrationDecoder::new(ENGLISH).unwrap();
+        assert_eq!(values(&english, "eightwo"), Some((8, 2)));
+        assert_eq!(values(&english, "xtwone3four"), Some((2, 4)));
+        assert_eq!(values(&english, "oneight"), Some((1, 8)));
+        assert_eq!(values(&english, "abc"), None);
+
+        let german = CalibrationDecoder::new(GERMAN).unwrap();
+        assert_eq!(values(&german, "zweineunacht"), Some((2, 8)));
+
+        let roman = CalibrationDecoder::new(ROMAN).unwrap();
+        assert_eq!(values(&roman, "xIVyVIII"), Some((4, 8)));
+
+        let custom = CalibrationDecoder::new(&[("ten", 0)]).unwrap();
+        assert_eq!(values(&custom, "7often"), Some((7, 0)));
+
+        assert_eq!(
+            CalibrationDecoder::new(&[("ten", 10)])
+                .err()
+                .map(|error| error.to_string()),
+            Some(String::from("\"ten\" stands for 10, which is not a digit"))
+        );
+
+        assert!(matches!(
+            CalibrationDecoder::new(&[("one", 1), ("", 5)]),
+            Err(VocabularyError::EmptyWord { value: 5 })
+        ));
+    }
+
+    #[test]
+    fn calibrations() {
+        let decoder = CalibrationDecoder::new(ENGLISH).unwrap();
+        let calibrations = decoder
+            .calibrations(parse_input(EXAMPLE_INPUT_2).unwrap())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(calibrations.len(), 7);
+        assert_eq!(
+            calibrations[6],
+            Calibration {
+                line: 7,
+                first: Digit {
+                    value: 7,
+                    offset: 0,
+                    spelled: false,
+                },
+                last: Digit {
+                    value: 6,
+                    offset: 6,
+                    spelled: true,
+                },
+            }
+        );
+        assert_eq!(calibrations[6].value(), 76);
+    }
+
+    #[test]
+    fn missing_digit() {
+        let input = parse_input("1abc2\nabc\n").unwrap();
+        let error =
+            sum_calibration_values(input, &CalibrationDecoder::new(&[]).unwrap()).unwrap_err();
+
+        assert_eq!(
+            error,
+            MissingDigit {
+                line: 2,
+                content: String::from("abc"),
+            }
+        );
+        assert_eq!(error.to_string(), "no digit in line 2: \"abc\"");
+
+        let error = Day01::part2(parse_input("1abc2\nabc\n").unwrap()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Day 01 Part 2: no digit in line 2: \"abc\""
+        );
+    }
+
+    #[test]
+    fn non_ascii_input() {
+        let error = parse_input("one2\nf\u{fc}nf3\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Day 01: non-ASCII character at line 2, column 2\n    f\u{fc}nf3\n     ^"
+        );
+    }
+
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(EXAMPLE_INPUT_1)), 142)
+        assert_eq!(solve1(parse_input(EXAMPLE_INPUT_1).unwrap()), Ok(142))
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(1, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(EXAMPLE_INPUT_2)), 281)
+        assert_eq!(solve2(parse_input(EXAMPLE_INPUT_2).unwrap()), Ok(281))
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(1, 2)
     }
 }
@@ -1,154 +1,342 @@
+use std::{collections::BTreeMap, error::Error, fmt};
+
 use winnow::{
-    combinator::{alt, opt, preceded, terminated},
+    ascii::{alpha1, newline},
+    combinator::{preceded, separated, separated_pair},
     error::{ErrMode, ErrorKind, ParserError},
-    token::tag,
-    PResult, Parser, ascii::newline,
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_u32},
 };
 
-use crate::utils::parse_u32;
+/// Number of cubes per colour
+pub type Bag<'s> = BTreeMap<&'s str, u32>;
 
-pub type Red = u32;
-pub type Green = u32;
-pub type Blue = u32;
+/// The bag of the puzzle
+pub const PUZZLE_LIMITS: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
 
+/// Cubes revealed in a single draw in the order the colours are first named, a colour
+/// named twice is added up
 #[derive(Default, Debug, PartialEq, Clone)]
-pub struct Hand(pub Red, pub Green, pub Blue);
+pub struct Hand<'s>(pub Vec<(&'s str, u32)>);
 
-pub type Round = Vec<Hand>;
+pub type Round<'s> = Vec<Hand<'s>>;
 
 #[derive(Debug, PartialEq, Clone)]
-pub struct Game(pub u32, pub Round);
+pub struct Game<'s>(pub u32, pub Round<'s>);
 
-pub type Games = Vec<Game>;
+pub type Games<'s> = Vec<Game<'s>>;
 
-pub fn solve1(games: Games) -> u32 {
-    let max_red: u32 = 12;
-    let max_green: u32 = 13;
-    let max_blue: u32 = 14;
+/// First draw of a game that needs more cubes of `color` than the limit allows
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ImpossibleDraw<'s> {
+    pub game: u32,
+    /// Index of the draw within the game
+    pub hand: usize,
+    pub color: &'s str,
+    pub count: u32,
+    pub limit: u32,
+}
 
-    games
-        .iter()
-        .filter(|game| {
-            !game.1.iter().any(|Hand(red, green, blue)| {
-                *red > max_red || *green > max_green || *blue > max_blue
+/// The power of the cubes does not fit into a `u64`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PowerOverflow;
+
+impl fmt::Display for PowerOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the power of the cubes does not fit into a u64")
+    }
+}
+
+impl Error for PowerOverflow {}
+
+impl<'s> Game<'s> {
+    /// Fewest cubes of each colour that make the game possible
+    pub fn minimal_bag(&self) -> Bag<'s> {
+        let mut bag = Bag::new();
+
+        for hand in self.1.iter() {
+            for &(color, count) in hand.0.iter() {
+                let needed = bag.entry(color).or_insert(0);
+                *needed = (*needed).max(count);
+            }
+        }
+
+        bag
+    }
+
+    /// Product of the minimal cube counts of all colours that show up in the game
+    pub fn power(&self) -> Result<u64, PowerOverflow> {
+        product(self.minimal_bag().into_values())
+    }
+
+    /// Product of the minimal cube counts of `colors`, a colour that never shows up counts as 0
+    pub fn power_of_colors(&self, colors: &[&str]) -> Result<u64, PowerOverflow> {
+        let bag = self.minimal_bag();
+
+        product(
+            colors
+                .iter()
+                .map(|color| bag.get(color).copied().unwrap_or(0)),
+        )
+    }
+
+    /// Colours missing from `limits` are not allowed at all
+    pub fn impossible_draw(&self, limits: &Bag) -> Option<ImpossibleDraw<'s>> {
+        self.1.iter().enumerate().find_map(|(index, hand)| {
+            hand.0.iter().find_map(|&(color, count)| {
+                let limit = limits.get(color).copied().unwrap_or(0);
+
+                (count > limit).then_some(ImpossibleDraw {
+                    game: self.0,
+                    hand: index,
+                    color,
+                    count,
+                    limit,
+                })
             })
         })
-        .map(|game| game.0)
-        .sum()
+    }
 }
 
-pub fn solve2(games: Games) -> u32 {
+pub fn solve1(games: Games, limits: &Bag) -> u32 {
     games
         .iter()
-        .map(|game| {
-            game.1.iter().fold(
-                (0, 0, 0),
-                |(max_red, max_green, max_blue), Hand(red, green, blue)| {
-                    (
-                        max_red.max(*red),
-                        max_green.max(*green),
-                        max_blue.max(*blue),
-                    )
-                },
-            )
-        })
-        .map(|colors| colors.0 * colors.1 * colors.2)
+        .filter(|game| game.impossible_draw(limits).is_none())
+        .map(|game| game.0)
         .sum()
 }
 
-fn parse_color<'s>(input: &mut &'s str) -> PResult<(u32, u32, u32)> {
-    let n = parse_u32(input)?;
-    let color = preceded(" ", alt(("blue", "green", "red"))).parse_next(input)?;
-
-    match color {
-        "blue" => Ok((0, 0, n)),
-        "green" => Ok((0, n, 0)),
-        "red" => Ok((n, 0, 0)),
-        _ => Err(ErrMode::from_error_kind(input, ErrorKind::Verify)),
-    }
+pub fn solve2(games: Games) -> Result<u64, PowerOverflow> {
+    games.iter().try_fold(0_u64, |sum, game| {
+        sum.checked_add(game.power()?).ok_or(PowerOverflow)
+    })
 }
 
-impl Hand {
-    pub fn parse<'s>(input: &mut &'s str) -> PResult<Hand> {
-        let mut hand = Hand::default();
+fn product(mut counts: impl Iterator<Item = u32>) -> Result<u64, PowerOverflow> {
+    counts.try_fold(1_u64, |product, count| {
+        product.checked_mul(u64::from(count)).ok_or(PowerOverflow)
+    })
+}
 
-        while let Some((red, green, blue)) =
-            opt(terminated(preceded(" ", parse_color), opt(','))).parse_next(input)?
-        {
-            hand.0 += red;
-            hand.1 += green;
-            hand.2 += blue;
-        }
+/// The games that can't be played with `limits` together with their first offending draw
+pub fn impossible_games<'s>(games: &[Game<'s>], limits: &Bag) -> Vec<ImpossibleDraw<'s>> {
+    games
+        .iter()
+        .filter_map(|game| game.impossible_draw(limits))
+        .collect()
+}
 
-        if hand == Hand::default() {
-            Err(ErrMode::from_error_kind(input, ErrorKind::Complete))
-        } else {
-            Ok(hand)
-        }
-    }
+pub fn puzzle_limits() -> Bag<'static> {
+    Bag::from(PUZZLE_LIMITS)
 }
 
-impl Game {
-    pub fn parse<'s>(input: &mut &'s str) -> PResult<Game> {
-        let _ = tag("Game ").parse_next(input)?;
-        let game_id = parse_u32(input)?;
-        let _ = tag(":").parse_next(input)?;
+fn parse_cubes<'s>(input: &mut &'s str) -> PResult<(u32, &'s str)> {
+    separated_pair(parse_u32, ' ', alpha1).parse_next(input)
+}
 
-        let mut rounds: Vec<Hand> = Vec::with_capacity(10);
+impl<'s> Hand<'s> {
+    pub fn parse(input: &mut &'s str) -> PResult<Hand<'s>> {
+        let cubes: Vec<(u32, &str)> =
+            separated(1.., preceded(' ', parse_cubes), ',').parse_next(input)?;
 
-        while let Some(round) = opt(terminated(Hand::parse, opt(';'))).parse_next(input)? {
-            rounds.push(round)
+        let mut hand: Vec<(&str, u32)> = Vec::with_capacity(cubes.len());
+        for (count, color) in cubes {
+            match hand.iter_mut().find(|(other, _)| *other == color) {
+                Some((_, total)) => {
+                    *total = total
+                        .checked_add(count)
+                        .ok_or_else(|| ErrMode::from_error_kind(input, ErrorKind::Verify))?;
+                }
+                None => hand.push((color, count)),
+            }
         }
 
-        Ok(Game(game_id, rounds))
+        Ok(Hand(hand))
     }
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<Game>> {
-    let mut games: Vec<Game> = Vec::with_capacity(100);
+impl<'s> Game<'s> {
+    pub fn parse(input: &mut &'s str) -> PResult<Game<'s>> {
+        let game_id = preceded("Game ", parse_u32).parse_next(input)?;
+        let rounds = preceded(':', separated(1.., Hand::parse, ';')).parse_next(input)?;
 
-    while let Some(round) = opt(terminated(Game::parse, opt(newline))).parse_next(input)? {
-        games.push(round)
+        Ok(Game(game_id, rounds))
     }
+}
 
-    Ok(games)
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Games<'s>, AocError> {
+    parse_complete(Day02::DAY, input, parse_puzzle)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::day02::{parse_input, solve1, solve2, Game, Hand};
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Games<'s>> {
+    separated(1.., Game::parse, newline).parse_next(input)
+}
 
-    const EXAMPLE_INPUT: &'static str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+pub const EXAMPLE_INPUT: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
 Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
 "#;
 
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "8"),
+        Example::new(2, EXAMPLE_INPUT, "2286"),
+    ];
+
+    type Input<'s> = Games<'s>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(u64::from(solve1(input, &puzzle_limits())))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve2(input).map_err(|error| AocError::solve(Self::DAY, 2, error))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day02::{
+            impossible_games, parse_input, puzzle_limits, solve1, solve2, Bag, Game, Hand,
+            ImpossibleDraw, PowerOverflow, EXAMPLE_INPUT,
+        },
+    };
+
+    fn hand<const N: usize>(cubes: [(&'static str, u32); N]) -> Hand<'static> {
+        Hand(cubes.to_vec())
+    }
+
     #[test]
     fn input_parsing() {
+        let games = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(games.len(), 5);
         assert_eq!(
-            parse_input(&mut EXAMPLE_INPUT),
-            Ok(
+            games[0],
+            Game(
+                1,
                 vec![
-                    Game(1, vec![Hand(4, 0, 3), Hand(1, 2, 6), Hand(0, 2, 0)]),
-                    Game(2, vec![Hand(0, 2, 1), Hand(1, 3, 4), Hand(0, 1, 1)]),
-                    Game(3, vec![Hand(20, 8, 6), Hand(4, 13, 5), Hand(1, 5, 0)]),
-                    Game(4, vec![Hand(3, 1, 6), Hand(6, 3, 0), Hand(14, 3, 15)]),
-                    Game(5, vec![Hand(6, 3, 1), Hand(1, 2, 2)])
+                    hand([("blue", 3), ("red", 4)]),
+                    hand([("red", 1), ("green", 2), ("blue", 6)]),
+                    hand([("green", 2)]),
                 ]
             )
         );
+
+        let mut input = "Game 7: 2 teal, 1 red, 3 teal; 4 violet";
+        assert_eq!(
+            parse_input(&mut input).unwrap(),
+            vec![Game(
+                7,
+                vec![hand([("teal", 5), ("red", 1)]), hand([("violet", 4)])]
+            )]
+        );
+    }
+
+    #[test]
+    fn queries() {
+        let games = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(
+            games[0].minimal_bag(),
+            Bag::from([("red", 4), ("green", 2), ("blue", 6)])
+        );
+        assert_eq!(games[2].power(), Ok(1560));
+        assert_eq!(
+            games[2].power_of_colors(&["red", "green", "blue"]),
+            Ok(1560)
+        );
+        assert_eq!(games[2].power_of_colors(&["red", "violet"]), Ok(0));
+
+        let teal = Game(7, vec![hand([("teal", 5)]), hand([("teal", 2)])]);
+        assert_eq!(teal.power(), Ok(5));
+
+        assert_eq!(
+            impossible_games(&games, &puzzle_limits()),
+            vec![
+                ImpossibleDraw {
+                    game: 3,
+                    hand: 0,
+                    color: "red",
+                    count: 20,
+                    limit: 12,
+                },
+                ImpossibleDraw {
+                    game: 4,
+                    hand: 2,
+                    color: "blue",
+                    count: 15,
+                    limit: 14,
+                },
+            ]
+        );
+
+        // The first offending colour in draw order is reported, not the alphabetically first
+        let mut input = "Game 9: 13 red, 15 blue";
+        let games = parse_input(&mut input).unwrap();
+        assert_eq!(
+            games[0]
+                .impossible_draw(&puzzle_limits())
+                .map(|draw| draw.color),
+            Some("red")
+        );
+    }
+
+    #[test]
+    fn large_powers() {
+        let mut input = "Game 1: 100 a, 100 b, 100 c, 100 d, 100 e";
+        assert_eq!(solve2(parse_input(&mut input).unwrap()), Ok(10_000_000_000));
+
+        let mut input = "Game 1: 4000000000 a, 4000000000 b, 4000000000 c";
+        assert_eq!(solve2(parse_input(&mut input).unwrap()), Err(PowerOverflow));
+
+        assert!(parse_input(&mut "Game 1: 4000000000 a, 4000000000 a").is_err());
     }
 
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 8)
+        let games = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(solve1(games.clone(), &puzzle_limits()), 8);
+        assert_eq!(
+            solve1(
+                games,
+                &Bag::from([("red", 20), ("green", 13), ("blue", 15)])
+            ),
+            15
+        );
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(2, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 2286)
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), Ok(2286))
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(2, 2)
     }
 }
@@ -1,113 +1,227 @@
-use itertools::Itertools;
-use std::str;
+use crate::{
+    error::{AocError, Location},
+    solution::{Example, Solution},
+    utils::{
+        geom::Point,
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
 
-type Input = Vec<Vec<u8>>;
-type Point = (usize, usize);
-
-pub fn solve1(input: Input) -> u32 {
-    input
-        .iter()
-        .enumerate()
-        .map(|(row_index, row)| {
-            row.iter()
-                .enumerate()
-                .filter(|(_, maybe_symbol)| {
-                    !(maybe_symbol.is_ascii_digit() || **maybe_symbol == ('.' as u8))
-                })
-                .map(|(column_index, _)| {
-                    touching_numbers(&input, (row_index, column_index))
-                        .iter()
-                        .sum::<u32>()
-                })
-                .sum::<u32>()
-        })
-        .sum()
+/// A number of the schematic, it covers the columns `start..end` of its row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
 }
 
-pub fn solve2(input: Input) -> u32 {
-    input
-    .iter()
-    .enumerate()
-    .map(|(row_index, row)| {
-        row.iter()
-            .enumerate()
-            .filter(|(_, maybe_symbol)| {
-                **maybe_symbol == ('*' as u8)
-            })
-            .map(|(column_index, _)| {
-                touching_numbers(&input, (row_index, column_index))
-            })
-            .filter(|numbers| numbers.len() == 2)
-            .map(|numbers| numbers[0] * numbers[1])
-            .sum::<u32>()
-    })
-    .sum()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: u8,
+    pub position: Point<usize>,
 }
 
-pub fn parse_input(input: &str) -> Input {
-    input.lines().map(|str| str.as_bytes().to_owned()).collect()
+/// Position of a number that doesn't fit into a `u32`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberTooLarge {
+    pub row: usize,
+    pub start: usize,
 }
 
-const LOOKUP_AREA: [(i32, i32); 8] = [
-    (-1, -1),
-    (0, -1),
-    (1, -1),
-    (-1, 0),
-    (1, 0),
-    (-1, 1),
-    (0, 1),
-    (1, 1),
-];
-
-fn touching_numbers(input: &[Vec<u8>], origin: Point) -> Vec<u32> {
-    lookup_digit(input, origin)
-        .iter()
-        .map(|source| scan_number(input, *source))
-        .unique()
-        .map(|(_, _, value)| value)
-        .collect()
+/// All numbers and symbols of an engine schematic and which of them are adjacent.
+///
+/// Both sides of the adjacency graph refer to the indices of `numbers` and `symbols`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    pub symbol_neighbours: Vec<Vec<usize>>,
+    pub number_neighbours: Vec<Vec<usize>>,
 }
 
-fn lookup_digit(input: &[Vec<u8>], origin: Point) -> Vec<Point> {
-    LOOKUP_AREA
-        .iter()
-        .map(|(row, column)| (row + origin.0 as i32, column + origin.1 as i32))
-        .filter(|(row, column)| {
-            *row >= 0
-                && *column >= 0
-                && (*row as usize) < input.len()
-                && (*column as usize) < input[*row as usize].len()
+impl Schematic {
+    /// Scans the grid once, numbers and symbols are linked as soon as the later one of them
+    /// is found. Fails on the first number that doesn't fit into a `u32`.
+    pub fn new(grid: &Grid<u8>) -> Result<Schematic, NumberTooLarge> {
+        let mut schematic = Schematic {
+            numbers: Vec::new(),
+            symbols: Vec::new(),
+            symbol_neighbours: Vec::new(),
+            number_neighbours: Vec::new(),
+        };
+        let mut number_at = Grid::filled(grid.width(), grid.height(), None);
+        let mut symbol_at = Grid::filled(grid.width(), grid.height(), None);
+
+        // Neighbours of the columns `start..end` that were already scanned: the row above
+        // including the diagonals and the cell to the left
+        let scanned_neighbours = |row: usize, start: usize, end: usize| {
+            let cols = start.saturating_sub(1)..=end.min(grid.width() - 1);
+            let above = row
+                .checked_sub(1)
+                .into_iter()
+                .flat_map(move |above| cols.clone().map(move |col| Point::new(above, col)));
+            let left = start.checked_sub(1).map(|col| Point::new(row, col));
+
+            above.chain(left)
+        };
+
+        for (row_index, row) in grid.rows().enumerate() {
+            let mut col = 0;
+
+            while col < row.len() {
+                let cell = row[col];
+
+                if cell.is_ascii_digit() {
+                    let start = col;
+                    let mut value = 0_u32;
+
+                    while col < row.len() && row[col].is_ascii_digit() {
+                        value = value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add((row[col] - b'0') as u32))
+                            .ok_or(NumberTooLarge {
+                                row: row_index,
+                                start,
+                            })?;
+                        number_at[Point::new(row_index, col)] = Some(schematic.numbers.len());
+                        col += 1;
+                    }
+
+                    let symbols = scanned_neighbours(row_index, start, col)
+                        .filter_map(|position| symbol_at[position])
+                        .collect();
+                    schematic.add_number(
+                        Number {
+                            value,
+                            row: row_index,
+                            start,
+                            end: col,
+                        },
+                        symbols,
+                    );
+                    continue;
+                }
+
+                if cell != b'.' {
+                    let mut numbers: Vec<usize> = scanned_neighbours(row_index, col, col + 1)
+                        .filter_map(|position| number_at[position])
+                        .collect();
+                    numbers.dedup();
+
+                    symbol_at[Point::new(row_index, col)] = Some(schematic.symbols.len());
+                    schematic.add_symbol(
+                        Symbol {
+                            symbol: cell,
+                            position: Point::new(row_index, col),
+                        },
+                        numbers,
+                    );
+                }
+                col += 1;
+            }
+        }
+
+        Ok(schematic)
+    }
+
+    fn add_number(&mut self, number: Number, symbols: Vec<usize>) {
+        for &symbol in symbols.iter() {
+            self.symbol_neighbours[symbol].push(self.numbers.len());
+        }
+
+        self.numbers.push(number);
+        self.number_neighbours.push(symbols);
+    }
+
+    fn add_symbol(&mut self, symbol: Symbol, numbers: Vec<usize>) {
+        for &number in numbers.iter() {
+            self.number_neighbours[number].push(self.symbols.len());
+        }
+
+        self.symbols.push(symbol);
+        self.symbol_neighbours.push(numbers);
+    }
+
+    /// Numbers next to at least one symbol
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers_where(|symbols| !symbols.is_empty())
+    }
+
+    /// Numbers that touch no symbol at all
+    pub fn unattached_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers_where(|symbols| symbols.is_empty())
+    }
+
+    /// Numbers next to any `symbol`, each number is reported once
+    pub fn numbers_next_to(&self, symbol: u8) -> impl Iterator<Item = &Number> + '_ {
+        self.numbers_where(move |symbols| {
+            symbols
+                .iter()
+                .any(|&index| self.symbols[index].symbol == symbol)
         })
-        .map(|(r, c)| (r as usize, c as usize))
-        .filter(|(row, column)| input[*row][*column].is_ascii_digit())
-        .collect()
-}
+    }
 
-fn scan_number(input: &[Vec<u8>], source: Point) -> (usize, usize, u32) {
-    let row = &input[source.0 as usize];
+    /// Numbers next to the symbol with the given index
+    pub fn neighbours(&self, symbol: usize) -> impl Iterator<Item = &Number> {
+        self.symbol_neighbours[symbol]
+            .iter()
+            .map(|&number| &self.numbers[number])
+    }
 
-    let mut begin: usize = source.1 as usize;
-    while begin > 0 && row[begin - 1].is_ascii_digit() {
-        begin -= 1;
+    /// Indices of the `symbol`s with exactly `count` adjacent numbers
+    pub fn gears(&self, symbol: u8, count: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len()).filter(move |&index| {
+            self.symbols[index].symbol == symbol && self.symbol_neighbours[index].len() == count
+        })
     }
 
-    let mut end: usize = source.1 as usize;
-    while end < row.len() && row[end].is_ascii_digit() {
-        end += 1;
+    fn numbers_where<'a>(
+        &'a self,
+        filter: impl Fn(&[usize]) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a Number> {
+        self.numbers
+            .iter()
+            .zip(self.number_neighbours.iter())
+            .filter(move |(_, symbols)| filter(symbols))
+            .map(|(number, _)| number)
     }
+}
 
-    (
-        begin,
-        end,
-        str::from_utf8(&row[begin..end]).unwrap().parse().unwrap(),
-    )
+pub fn solve1(input: Schematic) -> u64 {
+    input.part_numbers().map(|number| number.value as u64).sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::day03::{parse_input, solve1, solve2};
+/// The ratio of two gear numbers always fits into a `u64`
+pub fn solve2(input: Schematic) -> u64 {
+    input
+        .gears(b'*', 2)
+        .map(|gear| {
+            input
+                .neighbours(gear)
+                .map(|number| number.value as u64)
+                .product::<u64>()
+        })
+        .sum()
+}
+
+pub fn parse_input(input: &mut &str) -> Result<Schematic, AocError> {
+    let source = *input;
+    let grid = parse_complete(Day03::DAY, input, parse_grid)?;
+
+    // Rows are separated by a single newline
+    Schematic::new(&grid).map_err(|error| AocError::Parse {
+        day: Day03::DAY,
+        location: Location::new(
+            source,
+            &source[error.row * (grid.width() + 1) + error.start..],
+        ),
+        message: String::from("number does not fit into 32 bits"),
+    })
+}
 
-    const EXAMPLE_INPUT: &'static str = r#"467..114..
+pub const EXAMPLE_INPUT: &str = r#"467..114..
 ...*......
 ..35..633.
 ......#...
@@ -119,13 +233,112 @@ mod tests {
 .664.598..
 "#;
 
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "4361"),
+        Example::new(2, EXAMPLE_INPUT, "467835"),
+    ];
+
+    type Input<'s> = Schematic;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day03::{parse_input, solve1, solve2, Number, EXAMPLE_INPUT},
+        error::AocError,
+    };
+
+    #[test]
+    fn schematic() {
+        let schematic = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.symbols.len(), 6);
+        assert_eq!(
+            schematic.numbers[0],
+            Number {
+                value: 467,
+                row: 0,
+                start: 0,
+                end: 3,
+            }
+        );
+
+        let values = |numbers: Vec<&Number>| numbers.iter().map(|n| n.value).collect::<Vec<_>>();
+        assert_eq!(values(schematic.unattached_numbers().collect()), [114, 58]);
+        assert_eq!(values(schematic.numbers_next_to(b'#').collect()), [633]);
+        assert_eq!(
+            values(schematic.numbers_next_to(b'*').collect()),
+            [467, 35, 617, 755, 598]
+        );
+
+        let gears = schematic.gears(b'*', 2).collect::<Vec<_>>();
+        assert_eq!(gears.len(), 2);
+        assert_eq!(values(schematic.neighbours(gears[0]).collect()), [467, 35]);
+        assert_eq!(schematic.gears(b'*', 1).count(), 1);
+    }
+
+    #[test]
+    fn neighbours_in_every_direction() {
+        let schematic = parse_input(&mut "1.2.9\n3*4..\n5.6..\n.7...\n.*...\n.8...\n").unwrap();
+        let values = |numbers: Vec<&Number>| numbers.iter().map(|n| n.value).collect::<Vec<_>>();
+
+        assert_eq!(
+            values(schematic.neighbours(0).collect()),
+            [1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(values(schematic.neighbours(1).collect()), [7, 8]);
+        assert_eq!(values(schematic.unattached_numbers().collect()), [9]);
+    }
+
+    #[test]
+    fn large_numbers() {
+        let schematic = parse_input(&mut "4294967295*4294967295\n").unwrap();
+        assert_eq!(solve2(schematic), 4294967295 * 4294967295);
+
+        let error = parse_input(&mut "1*2.........\n.*4294967296\n").unwrap_err();
+        assert!(matches!(
+            error,
+            AocError::Parse { location, .. } if (location.line, location.column) == (2, 3)
+        ));
+    }
+
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(EXAMPLE_INPUT)), 4361)
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 4361)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(3, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(EXAMPLE_INPUT)), 467835)
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 467835)
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(3, 2)
     }
 }
@@ -1,87 +1,171 @@
-use std::collections::BTreeSet;
+use std::{error::Error, fmt};
 
 use winnow::{
     ascii::{newline, space0, space1},
     combinator::{opt, preceded, terminated},
-    token::tag,
     PResult, Parser,
 };
 
-use crate::utils::{parse_aligned_u32, parse_u32};
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_aligned_u32, parse_complete, parse_u32},
+};
 
 type ID = usize;
 
-pub struct Card(pub ID, BTreeSet<u32>, BTreeSet<u32>);
+/// Set of the numbers `0..128`, one bit each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NumberSet(pub u128);
+
+impl NumberSet {
+    pub const CAPACITY: u32 = u128::BITS;
+
+    /// Returns `false` and leaves the set unchanged if `number` does not fit into the set
+    pub fn insert(&mut self, number: u32) -> bool {
+        if number >= Self::CAPACITY {
+            return false;
+        }
+
+        self.0 |= 1 << number;
+        true
+    }
+
+    pub fn contains(&self, number: u32) -> bool {
+        number < Self::CAPACITY && self.0 & (1 << number) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
 
-pub fn solve1(input: Vec<Card>) -> u32 {
+    pub fn intersection(&self, other: &NumberSet) -> NumberSet {
+        NumberSet(self.0 & other.0)
+    }
+}
+
+pub struct Card(pub ID, pub NumberSet, pub NumberSet);
+
+impl Card {
+    /// Number of picked numbers that are winning numbers
+    pub fn matches(&self) -> usize {
+        self.1.intersection(&self.2).len()
+    }
+}
+
+/// The points of the cards do not fit into a `usize`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointsOverflow;
+
+impl fmt::Display for PointsOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the points of the cards do not fit into a usize")
+    }
+}
+
+impl Error for PointsOverflow {}
+
+/// How a single card was copied while playing the scratchcards in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardCopies {
+    pub id: ID,
+    /// Instances of the card, including the original
+    pub copies: usize,
+    /// Copies of later cards won by all instances of this card
+    pub produced: usize,
+    /// Earlier cards together with the number of copies of this card they handed out
+    pub sources: Vec<(ID, usize)>,
+}
+
+pub fn solve1(input: Vec<Card>) -> Result<usize, PointsOverflow> {
     input
-        .into_iter()
-        .map(|Card(_, winning_numbers, picked_numbers)| {
-            winning_numbers
-                .intersection(&picked_numbers)
-                .fold(0, |score, _| if score == 0 { 1 } else { score * 2 })
+        .iter()
+        .try_fold(0_usize, |sum, card| {
+            let points = match card.matches() {
+                0 => 0,
+                matches => 1_usize.checked_shl(matches as u32 - 1)?,
+            };
+
+            sum.checked_add(points)
         })
-        .sum()
+        .ok_or(PointsOverflow)
 }
 
-pub fn solve2(input: Vec<Card>) -> u32 {
-    let mut cards = Vec::<usize>::with_capacity(input.len());
-    for i in 0..input.len() {
-        cards.insert(i, 1)
-    }
+pub fn solve2(input: Vec<Card>) -> usize {
+    copy_cascade(&input).iter().map(|card| card.copies).sum()
+}
+
+/// Plays all cards in order, every instance of a card with `n` matches wins one copy of
+/// each of the next `n` cards.
+pub fn copy_cascade(cards: &[Card]) -> Vec<CardCopies> {
+    let mut cascade = cards
+        .iter()
+        .map(|card| CardCopies {
+            id: card.0,
+            copies: 1,
+            produced: 0,
+            sources: Vec::new(),
+        })
+        .collect::<Vec<_>>();
 
-    for Card(id, winning_numbers, picked_numbers) in input {
-        let won_cards = winning_numbers.intersection(&picked_numbers).count();
+    for (index, card) in cards.iter().enumerate() {
+        let copies = cascade[index].copies;
+        let won = (index + 1..index + 1 + card.matches()).take_while(|&next| next < cards.len());
 
-        for idx in id..(id + won_cards).min(cards.len()) {
-            cards[idx] = cards[idx] + cards[id - 1];
+        for next in won {
+            cascade[next].copies += copies;
+            cascade[next].sources.push((card.0, copies));
+            cascade[index].produced += copies;
         }
     }
 
-    cards.iter().sum::<usize>() as u32
+    cascade
 }
 
-impl Card {
-    pub fn parse<'s>(input: &mut &'s str) -> PResult<Card> {
-        let _ = tag("Card ").parse_next(input)?;
-        let id = parse_aligned_u32(input)?;
-        let _ = tag(":").parse_next(input)?;
-
-        let mut left = BTreeSet::new();
-        while let Some(number) = opt(preceded(space1, parse_u32)).parse_next(input)? {
-            left.insert(number);
-        }
+fn parse_numbers(input: &mut &str) -> PResult<NumberSet> {
+    let mut numbers = NumberSet::default();
 
-        let _ = preceded(space0, '|').parse_next(input)?;
+    while let Some(number) = opt(preceded(
+        space1,
+        parse_u32.verify(|&number| number < NumberSet::CAPACITY),
+    ))
+    .parse_next(input)?
+    {
+        // The parser only accepts numbers that fit
+        numbers.insert(number);
+    }
 
-        let mut right = BTreeSet::new();
-        while let Some(number) = opt(preceded(space1, parse_u32)).parse_next(input)? {
-            right.insert(number);
-        }
+    Ok(numbers)
+}
+
+impl Card {
+    pub fn parse(input: &mut &str) -> PResult<Card> {
+        let id = preceded("Card ", parse_aligned_u32).parse_next(input)?;
+        let left = preceded(':', parse_numbers).parse_next(input)?;
+        let right = preceded((space0, '|'), parse_numbers).parse_next(input)?;
 
         Ok(Card(id as usize, left, right))
     }
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<Card>> {
+pub fn parse_input(input: &mut &str) -> Result<Vec<Card>, AocError> {
+    parse_complete(Day04::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Vec<Card>> {
     let mut games: Vec<Card> = Vec::with_capacity(211);
     while let Some(round) = opt(terminated(Card::parse, newline)).parse_next(input)? {
         games.push(round)
     }
 
-    if input.len() > 0 {
-        panic!("Could not fully parse input file");
-    }
-
     Ok(games)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::day04::{parse_input, solve1, solve2};
-
-    const EXAMPLE_INPUT: &'static str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+pub const EXAMPLE_INPUT: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
 Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
@@ -89,13 +173,138 @@ Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
 Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 "#;
 
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "13"),
+        Example::new(2, EXAMPLE_INPUT, "30"),
+    ];
+
+    type Input<'s> = Vec<Card>;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve1(input).map_err(|error| AocError::solve(Self::DAY, 1, error))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day04::{
+            copy_cascade, parse_input, solve1, solve2, CardCopies, NumberSet, PointsOverflow,
+            EXAMPLE_INPUT,
+        },
+        error::AocError,
+    };
+
+    #[test]
+    fn input_parsing() {
+        let cards = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(cards.len(), 6);
+        assert!(cards[0].1.contains(41) && cards[0].2.contains(6));
+        assert_eq!((cards[0].1.len(), cards[0].2.len()), (5, 8));
+        assert_eq!(
+            cards.iter().map(|card| card.matches()).collect::<Vec<_>>(),
+            [4, 2, 2, 1, 0, 0]
+        );
+
+        assert!(parse_input(&mut "Card 1: 41 128 | 83 86\n").is_err());
+        assert!(parse_input(&mut "Card 1: 99999999999 | 1\n").is_err());
+    }
+
+    #[test]
+    fn number_set() {
+        let mut numbers = NumberSet::default();
+
+        assert!(numbers.insert(127));
+        assert!(!numbers.insert(128));
+        assert_eq!(numbers, NumberSet(1 << 127));
+        assert!(!numbers.contains(128));
+    }
+
+    #[test]
+    fn cascade() {
+        let cascade = copy_cascade(&parse_input(&mut EXAMPLE_INPUT).unwrap());
+
+        assert_eq!(
+            cascade.iter().map(|card| card.copies).collect::<Vec<_>>(),
+            [1, 2, 4, 8, 14, 1]
+        );
+        assert_eq!(
+            cascade[4],
+            CardCopies {
+                id: 5,
+                copies: 14,
+                produced: 0,
+                sources: vec![(1, 1), (3, 4), (4, 8)],
+            }
+        );
+        assert_eq!(cascade[2].produced, 8);
+    }
+
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 13)
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), Ok(13))
+    }
+
+    #[test]
+    fn many_matches() {
+        let card = |matches: u32| {
+            let numbers = (0..matches)
+                .map(|number| number.to_string())
+                .collect::<Vec<_>>();
+            format!("Card 1: {} | {}\n", numbers.join(" "), numbers.join(" "))
+        };
+
+        let points = solve1(parse_input(&mut card(64).as_str()).unwrap());
+        assert_eq!(points, Ok(1 << 63));
+
+        let points = solve1(parse_input(&mut card(70).as_str()).unwrap());
+        assert_eq!(points, Err(PointsOverflow));
+
+        let twice = card(64).repeat(2);
+        assert_eq!(
+            solve1(parse_input(&mut twice.as_str()).unwrap()),
+            Err(PointsOverflow)
+        );
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(4, 1)
     }
 
     #[test]
     fn part2() {
         assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 30)
     }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(4, 2)
+    }
+
+    #[test]
+    fn leftover_input() {
+        let mut input = "Card 1: 41 48 | 83 86\nCard 2 13 32 | 61 30\n";
+
+        assert!(matches!(
+            parse_input(&mut input),
+            Err(AocError::LeftoverInput { day: 4, location }) if location.line == 2 && location.column == 1
+        ));
+    }
 }
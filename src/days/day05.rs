@@ -1,5 +1,3 @@
-use std::ops::Range;
-
 use itertools::Itertools;
 use rangemap::RangeMap;
 use winnow::{
@@ -9,7 +7,11 @@ use winnow::{
     PResult, Parser,
 };
 
-use crate::utils::{parse_u32, parse_u64};
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{interval::Interval, parse_complete, parse_u32, parse_u64},
+};
 
 type Input = (Vec<u64>, [RangeMap<u64, (u64, u64)>; 7]);
 
@@ -39,7 +41,7 @@ pub fn solve2((seeds, range_maps): Input) -> u64 {
             range_maps
                 .iter()
                 .fold(
-                    vec![seed_start..(seed_start + seed_len)],
+                    vec![Interval::new(seed_start, seed_start + seed_len)],
                     |src_ranges, range_map| {
                         src_ranges
                             .into_iter()
@@ -56,18 +58,20 @@ pub fn solve2((seeds, range_maps): Input) -> u64 {
         .unwrap()
 }
 
-fn intersection(range_map: &RangeMap<u64, (u64, u64)>, src_range: Range<u64>) -> Vec<Range<u64>> {
+fn intersection(
+    range_map: &RangeMap<u64, (u64, u64)>,
+    src_range: Interval<u64>,
+) -> Vec<Interval<u64>> {
     let mut result = Vec::new();
 
-    for (overlap_range, &(dst_start, src_start)) in range_map.overlapping(&src_range) {
-        let start = src_range.start.max(overlap_range.start) - src_start + dst_start;
-        let end = src_range.end.min(overlap_range.end) - src_start + dst_start;
-
-        result.push(start..end);
+    for (overlap_range, &(dst_start, src_start)) in range_map.overlapping(&src_range.into()) {
+        if let Some(overlap) = src_range.intersect(&overlap_range.clone().into()) {
+            result.push(overlap.translate(src_start, dst_start));
+        }
     }
 
-    for gap in range_map.gaps(&src_range) {
-        result.push(gap);
+    for gap in range_map.gaps(&src_range.into()) {
+        result.push(gap.into());
     }
 
     result
@@ -97,7 +101,11 @@ fn parse_map<'s>(name: &'static str, input: &mut &'s str) -> PResult<RangeMap<u6
     Ok(range_map)
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Input> {
+pub fn parse_input(input: &mut &str) -> Result<Input, AocError> {
+    parse_complete(Day05::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Input> {
     let _ = tag("seeds: ").parse_next(input)?;
     let seeds: Vec<u64> = terminated(separated(0.., parse_u64, ' '), newline).parse_next(input)?;
     let _ = newline(input)?;
@@ -112,22 +120,10 @@ pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Input> {
         parse_map("humidity-to-location map:", input)?
     ];
 
-    if input.len() > 0 {
-        panic!("Could not fully parse input file");
-    }
-
     Ok((seeds, maps))
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day05::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"seeds: 79 14 55 13
+pub const EXAMPLE_INPUT: &str = r#"seeds: 79 14 55 13
 
 seed-to-soil map:
 50 98 2
@@ -162,6 +158,39 @@ humidity-to-location map:
 56 93 4
 "#;
 
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "35"),
+        Example::new(2, EXAMPLE_INPUT, "46"),
+    ];
+
+    type Input<'s> = Input;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day05::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 35)
@@ -169,8 +198,7 @@ humidity-to-location map:
 
     #[test]
     fn solve_part1() {
-        let input = read_input(5, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(5, 1)
     }
 
     #[test]
@@ -180,7 +208,6 @@ humidity-to-location map:
 
     #[test]
     fn solve_part2() {
-        let input = read_input(5, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(5, 2)
     }
 }
@@ -6,7 +6,11 @@ use winnow::{
     PResult, Parser,
 };
 
-use crate::utils::parse_u64;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_u64},
+};
 
 pub struct Input(pub Vec<u64>, pub Vec<u64>);
 
@@ -64,7 +68,11 @@ fn solve_limits(time: u64, distance: u64) -> (u64, u64) {
     (x1, x2)
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Input> {
+pub fn parse_input(input: &mut &str) -> Result<Input, AocError> {
+    parse_complete(Day06::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Input> {
     let _ = tag("Time: ").parse_next(input)?;
     let _ = space1(input)?;
 
@@ -84,18 +92,43 @@ pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Input> {
     Ok(Input(times, distances))
 }
 
+pub const EXAMPLE_INPUT: &str = r#"Time:      7  15   30
+Distance:  9  40  200
+"#;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "288"),
+        Example::new(2, EXAMPLE_INPUT, "71503"),
+    ];
+
+    type Input<'s> = Input;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
 #[allow(const_item_mutation)]
 #[cfg(test)]
 mod tests {
     use crate::{
-        day06::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
+        answers::assert_answer,
+        day06::{parse_input, solve1, solve2, EXAMPLE_INPUT},
     };
 
-    const EXAMPLE_INPUT: &'static str = r#"Time:      7  15   30
-Distance:  9  40  200
-"#;
-
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 288)
@@ -103,8 +136,7 @@ Distance:  9  40  200
 
     #[test]
     fn solve_part1() {
-        let input = read_input(6, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(6, 1)
     }
 
     #[test]
@@ -114,7 +146,6 @@ Distance:  9  40  200
 
     #[test]
     fn solve_part2() {
-        let input = read_input(6, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(6, 2)
     }
 }
@@ -3,7 +3,11 @@ use std::cmp::Ordering;
 use itertools::Itertools;
 use winnow::{PResult, combinator::{terminated, separated, separated_pair}, ascii::{newline, alphanumeric1}, Parser};
 
-use crate::utils::parse_u32;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_u32},
+};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
@@ -160,28 +164,57 @@ impl HandType {
     }
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<(&'s str, u32)>> {
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Vec<(&'s str, u32)>, AocError> {
+    parse_complete(Day07::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Vec<(&'s str, u32)>> {
     terminated(
         separated(0.., separated_pair(alphanumeric1, ' ', parse_u32), newline),
         newline,
     ).parse_next(input)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day07::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"32T3K 765
+pub const EXAMPLE_INPUT: &str = r#"32T3K 765
 T55J5 684
 KK677 28
 KTJJT 220
 QQQJA 483
 "#;
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "6440"),
+        Example::new(2, EXAMPLE_INPUT, "5905"),
+    ];
+
+    type Input<'s> = Vec<(&'s str, u32)>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day07::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 6440)
@@ -189,8 +222,7 @@ QQQJA 483
 
     #[test]
     fn solve_part1() {
-        let input = read_input(7, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(7, 1)
     }
 
     #[test]
@@ -200,7 +232,6 @@ QQQJA 483
 
     #[test]
     fn solve_part2() {
-        let input = read_input(7, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(7, 2)
     }
 }
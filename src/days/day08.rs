@@ -9,6 +9,12 @@ use winnow::{
     PResult, Parser,
 };
 
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::parse_complete,
+};
+
 type Node = u16;
 
 type Input<'s> = (&'s str, HashMap<&'s str, Node>, Vec<(Node, Node, bool)>);
@@ -62,7 +68,11 @@ fn find_steps_to_end_node<'s>((directions, _, edges): &Input<'s>, start_node: No
     position
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Input<'s>> {
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Input<'s>, AocError> {
+    parse_complete(Day08::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Input<'s>> {
     let directions = take_till(0.., '\n').parse_next(input)?;
     let _ = newline(input)?;
     let _ = newline(input)?;
@@ -93,15 +103,7 @@ fn parse_row<'s>(input: &mut &'s str) -> PResult<(&'s str, &'s str, &'s str)> {
     Ok((name, left, right))
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day08::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT_1: &'static str = r#"RL
+pub const EXAMPLE_INPUT_1: &str = r#"RL
 
 AAA = (BBB, CCC)
 BBB = (DDD, EEE)
@@ -112,14 +114,14 @@ GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)
 "#;
 
-    const EXAMPLE_INPUT_2: &'static str = r#"LLR
+pub const EXAMPLE_INPUT_2: &str = r#"LLR
 
 AAA = (BBB, BBB)
 BBB = (AAA, ZZZ)
 ZZZ = (ZZZ, ZZZ)
 "#;
 
-    const EXAMPLE_INPUT_3: &'static str = r#"LR
+pub const EXAMPLE_INPUT_3: &str = r#"LR
 
 11A = (11B, XXX)
 11B = (XXX, 11Z)
@@ -131,6 +133,40 @@ ZZZ = (ZZZ, ZZZ)
 XXX = (XXX, XXX)
 "#;
 
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT_1, "2"),
+        Example::new(1, EXAMPLE_INPUT_2, "6"),
+        Example::new(2, EXAMPLE_INPUT_3, "6"),
+    ];
+
+    type Input<'s> = Input<'s>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day08::{parse_input, solve1, solve2, EXAMPLE_INPUT_1, EXAMPLE_INPUT_2, EXAMPLE_INPUT_3},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_1).unwrap()), 2);
@@ -139,8 +175,7 @@ XXX = (XXX, XXX)
 
     #[test]
     fn solve_part1() {
-        let input = read_input(8, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(8, 1)
     }
 
     #[test]
@@ -150,7 +185,6 @@ XXX = (XXX, XXX)
 
     #[test]
     fn solve_part2() {
-        let input = read_input(8, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(8, 2)
     }
 }
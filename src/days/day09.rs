@@ -4,12 +4,16 @@ use winnow::{
     PResult, Parser,
 };
 
-use crate::utils::parse_i32;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_i32},
+};
 
 pub fn solve1(input: Vec<Vec<i32>>) -> i32 {
     input
         .into_iter()
-        .map(|data| lagrange_interpolate(&data, data.len() as i32))
+        .map(|data| lagrange_interpolate(&data, data.len() as i64) as i32)
         .sum()
 }
 
@@ -18,12 +22,16 @@ pub fn solve2(input: Vec<Vec<i32>>) -> i32 {
         .into_iter()
         .map(|mut data| {
             data.reverse();
-            lagrange_interpolate(&data, data.len() as i32)
+            lagrange_interpolate(&data, data.len() as i64) as i32
         })
         .sum()
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<Vec<i32>>> {
+pub fn parse_input(input: &mut &str) -> Result<Vec<Vec<i32>>, AocError> {
+    parse_complete(Day09::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Vec<Vec<i32>>> {
     let mut lines: Vec<Vec<i32>> = Vec::with_capacity(200);
 
     while let Some(line) = opt(terminated(parse_line, newline)).parse_next(input)? {
@@ -43,36 +51,88 @@ pub fn parse_line<'s>(input: &mut &'s str) -> PResult<Vec<i32>> {
     Ok(values)
 }
 
-fn lagrange_interpolate(y: &[i32], xi: i32) -> i32 {
-    let mut result: f64 = 0.0;
+/// Evaluates the polynomial through `(0, y[0]), (1, y[1]), ...` at `xi`.
+///
+/// The weight of `y[i]` is `binomial(n - 1, i)` times the Lagrange numerator over
+/// `(n - 1)!`, which keeps everything in exact integer arithmetic.
+pub fn lagrange_interpolate<T: Copy + Into<i128>>(y: &[T], xi: i64) -> i128 {
+    let n = y.len() as i128;
+    let xi = xi as i128;
+
+    let mut sum: i128 = 0;
+    let mut binomial: i128 = 1;
+    let mut factorial: i128 = 1;
 
-    for x_i in 0..y.len() {
-        let mut term: f64 = y[x_i] as f64;
+    for x_i in 0..n {
+        let mut term = y[x_i as usize].into() * binomial;
 
-        for x_j in 0..y.len() {
+        for x_j in 0..n {
             if x_i != x_j {
-                term *= (xi - x_j as i32) as f64 / (x_i as i32 - x_j as i32) as f64;
+                term *= xi - x_j;
             }
         }
 
-        result += term;
+        if (n - 1 - x_i) % 2 == 1 {
+            term = -term;
+        }
+
+        sum += term;
+        binomial = binomial * (n - 1 - x_i) / (x_i + 1);
+        if x_i > 0 {
+            factorial *= x_i;
+        }
+    }
+
+    sum / factorial
+}
+
+pub const EXAMPLE_INPUT: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "114"),
+        Example::new(2, EXAMPLE_INPUT, "2"),
+    ];
+
+    type Input<'s> = Vec<Vec<i32>>;
+    type Output = i32;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
     }
 
-    result.round() as i32
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
 }
 
 #[allow(const_item_mutation)]
 #[cfg(test)]
 mod tests {
     use crate::{
-        day09::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
+        answers::assert_answer,
+        day09::{lagrange_interpolate, parse_input, solve1, solve2, EXAMPLE_INPUT},
     };
 
-    const EXAMPLE_INPUT: &'static str = r#"0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-"#;
+    #[test]
+    fn interpolate() {
+        assert_eq!(
+            lagrange_interpolate(&[1, 3, 7], 202300),
+            202300 * 202300 + 202300 + 1
+        );
+        assert_eq!(lagrange_interpolate(&[-8_i32, -1, 0, 1, 8], -3), -125);
+        assert_eq!(lagrange_interpolate(&[5_u64], 42), 5);
+    }
 
     #[test]
     fn part1() {
@@ -81,8 +141,7 @@ mod tests {
 
     #[test]
     fn solve_part1() {
-        let input = read_input(9, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(9, 1)
     }
 
     #[test]
@@ -92,7 +151,6 @@ mod tests {
 
     #[test]
     fn solve_part2() {
-        let input = read_input(9, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(9, 2)
     }
 }
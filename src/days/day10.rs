@@ -1,7 +1,16 @@
 use itertools::Itertools;
 
-type Point = (u16, u16);
-type Input<'b> = Vec<&'b [u8]>;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        geom::{interior_points, Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
+
+type Input = Grid<u8>;
 
 pub fn solve1(input: Input) -> usize {
     let mut distance: usize = 1;
@@ -32,7 +41,7 @@ pub fn solve2(input: Input) -> u32 {
     polygon_vertices.push(start);
 
     while current_point != start {
-        let current_symbol = input[current_point.0 as usize][current_point.1 as usize];
+        let current_symbol = input[current_point];
         distance += 1;
 
         if current_symbol == b'F'
@@ -49,118 +58,84 @@ pub fn solve2(input: Input) -> u32 {
             (if p1 == previous_point { p2 } else { p1 }, current_point);
     }
 
-    // Shoelace algorithm:
-    let mut sum_1 = 0_u32;
-    let mut sum_2 = 0_u32;
-    for i in 0..polygon_vertices.len() - 1 {
-        sum_1 += polygon_vertices[i].0 as u32 * polygon_vertices[i + 1].1 as u32;
-        sum_2 += polygon_vertices[i].1 as u32 * polygon_vertices[i + 1].0 as u32;
-    }
-
-    sum_1 += polygon_vertices[polygon_vertices.len() - 1].0 as u32 * polygon_vertices[0].1 as u32;
-    sum_2 += polygon_vertices[0].0 as u32 * polygon_vertices[polygon_vertices.len() - 1].1 as u32;
-
-    let area = sum_1.abs_diff(sum_2) / 2;
+    let vertices = polygon_vertices
+        .into_iter()
+        .map(|point| point.map(|value| value as i64))
+        .collect_vec();
 
-    area - distance / 2 + 1
+    interior_points(&vertices, distance as i64) as u32
 }
 
-fn find_next_segments(input: &Input, start_point: Point) -> (Point, Point) {
-    let mut segments = Vec::with_capacity(2);
-
-    for point in [
-        (start_point.0 + 1, start_point.1 + 0),
-        (start_point.0.checked_sub(1).unwrap_or(0), start_point.1 + 0),
-        (start_point.0 + 0, start_point.1 + 1),
-        (start_point.0 + 0, start_point.1.checked_sub(1).unwrap_or(0)),
-    ] {
-        if let Some((p1, p2)) = connecting_segments(&input, point) {
-            if p1 == start_point || p2 == start_point {
-                segments.push(point);
-            }
-        }
-    }
+fn find_next_segments(input: &Input, start_point: Point<usize>) -> (Point<usize>, Point<usize>) {
+    let segments = input
+        .neighbours4(start_point)
+        .filter(|&point| {
+            connecting_segments(input, point)
+                .is_some_and(|(p1, p2)| p1 == start_point || p2 == start_point)
+        })
+        .collect_vec();
 
     (segments[0], segments[1])
 }
 
-fn find_start(input: &Input) -> Point {
-    for (row_index, row) in input.into_iter().enumerate() {
-        if let Some((col_index, _)) = row.into_iter().find_position(|symbol| **symbol == b'S') {
-            return (row_index as u16, col_index as u16);
-        }
-    }
-
-    panic!("No starting position found!");
+fn find_start(input: &Input) -> Point<usize> {
+    input
+        .positions()
+        .find(|&position| input[position] == b'S')
+        .expect("No starting position found!")
 }
 
-fn connecting_segments(input: &Input, (row, col): Point) -> Option<(Point, Point)> {
-    match input[row as usize][col as usize] {
-        b'|' if row > 0 => Some(((row - 1, col), (row + 1, col))),
-        b'-' if col > 0 => Some(((row, col + 1), (row, col - 1))),
-        b'L' if row > 0 => Some(((row - 1, col), (row, col + 1))),
-        b'J' if row > 0 && col > 0 => Some(((row - 1, col), (row, col - 1))),
-        b'7' if col > 0 => Some(((row + 1, col), (row, col - 1))),
-        b'F' => Some(((row + 1, col), (row, col + 1))),
-        _ => None,
-    }
-}
+/// The two positions a pipe connects, `None` for ground or pipes leading out of the grid
+fn connecting_segments(
+    input: &Input,
+    position: Point<usize>,
+) -> Option<(Point<usize>, Point<usize>)> {
+    let (first, second) = match input[position] {
+        b'|' => (Direction::Up, Direction::Down),
+        b'-' => (Direction::Right, Direction::Left),
+        b'L' => (Direction::Up, Direction::Right),
+        b'J' => (Direction::Up, Direction::Left),
+        b'7' => (Direction::Down, Direction::Left),
+        b'F' => (Direction::Down, Direction::Right),
+        _ => return None,
+    };
 
-pub fn parse_input(input: &str) -> Input {
-    input.lines().map(|line| line.as_bytes()).collect_vec()
+    Some((input.step(position, first)?, input.step(position, second)?))
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day10::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
+pub fn parse_input(input: &mut &str) -> Result<Input, AocError> {
+    parse_complete(Day10::DAY, input, parse_grid)
+}
 
-    const EXAMPLE_INPUT_1: &'static str = r#".....
+pub const EXAMPLE_INPUT_1: &str = r#".....
 .S-7.
 .|.|.
 .L-J.
 .....
 "#;
 
-    const EXAMPLE_INPUT_2: &'static str = r#"-L|F7
+pub const EXAMPLE_INPUT_2: &str = r#"-L|F7
 7S-7|
 L|7||
 -L-J|
 L|-JF
 "#;
 
-    const EXAMPLE_INPUT_3: &'static str = r#"..F7.
+pub const EXAMPLE_INPUT_3: &str = r#"..F7.
 .FJ|.
 SJ.L7
 |F--J
 LJ...
 "#;
 
-    const EXAMPLE_INPUT_4: &'static str = r#"7-F7-
+pub const EXAMPLE_INPUT_4: &str = r#"7-F7-
 .FJ|7
 SJLL7
 |F--J
 LJ.LJ
 "#;
 
-    #[test]
-    fn part1() {
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_1)), 4);
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_2)), 4);
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_3)), 8);
-        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_4)), 8);
-    }
-
-    #[test]
-    fn solve_part1() {
-        let input = read_input(10, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str())));
-    }
-
-    const EXAMPLE_INPUT_5: &'static str = r#"...........
+pub const EXAMPLE_INPUT_5: &str = r#"...........
 .S-------7.
 .|F-----7|.
 .||.....||.
@@ -171,7 +146,7 @@ LJ.LJ
 ...........
 "#;
 
-    const EXAMPLE_INPUT_6: &'static str = r#".F----7F7F7F7F-7....
+pub const EXAMPLE_INPUT_6: &str = r#".F----7F7F7F7F-7....
 .|F--7||||||||FJ....
 .||.FJ||||||||L7....
 FJL7L7LJLJ||LJ.L-7..
@@ -183,7 +158,7 @@ L--J.L7...LJS7F-7L7.
 ....L---J.LJ.LJLJ...
 "#;
 
-    const EXAMPLE_INPUT_7: &'static str = r#"FF7FSF7F7F7F7F7F---7
+pub const EXAMPLE_INPUT_7: &str = r#"FF7FSF7F7F7F7F7F---7
 L|LJ||||||||||||F--J
 FL-7LJLJ||||||LJL-77
 F--JF--7||LJLJ7F7FJ-
@@ -195,7 +170,7 @@ L.L7LFJ|||||FJL7||LJ
 L7JLJL-JLJLJL--JLJ.L
 "#;
 
-    const EXAMPLE_INPUT_8: &'static str = r#"...........
+pub const EXAMPLE_INPUT_8: &str = r#"..........
 .S------7.
 .|F----7|.
 .||....||.
@@ -206,17 +181,71 @@ L7JLJL-JLJLJL--JLJ.L
 ..........
 "#;
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT_1, "4"),
+        Example::new(1, EXAMPLE_INPUT_2, "4"),
+        Example::new(1, EXAMPLE_INPUT_3, "8"),
+        Example::new(1, EXAMPLE_INPUT_4, "8"),
+        Example::new(2, EXAMPLE_INPUT_5, "4"),
+        Example::new(2, EXAMPLE_INPUT_8, "4"),
+        Example::new(2, EXAMPLE_INPUT_6, "8"),
+        Example::new(2, EXAMPLE_INPUT_7, "10"),
+    ];
+
+    type Input<'s> = Input;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input) as usize)
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day10::{
+            parse_input, solve1, solve2, EXAMPLE_INPUT_1, EXAMPLE_INPUT_2, EXAMPLE_INPUT_3,
+            EXAMPLE_INPUT_4, EXAMPLE_INPUT_5, EXAMPLE_INPUT_6, EXAMPLE_INPUT_7, EXAMPLE_INPUT_8,
+        },
+    };
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_1).unwrap()), 4);
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_2).unwrap()), 4);
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_3).unwrap()), 8);
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_4).unwrap()), 8);
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(10, 1)
+    }
+
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_5)), 4);
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_8)), 4);
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_6)), 8);
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_7)), 10);
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_5).unwrap()), 4);
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_8).unwrap()), 4);
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_6).unwrap()), 8);
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT_7).unwrap()), 10);
     }
 
     #[test]
     fn solve_part2() {
-        let input = read_input(10, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str())))
+        assert_answer(10, 2)
     }
 }
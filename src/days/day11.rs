@@ -1,7 +1,14 @@
 use itertools::Itertools;
 
+use crate::{
+    error::{AocError, Location},
+    solution::{Example, Solution},
+};
+
 type Point = (u64, u64);
-type Input = (Vec<(Point, u8)>, Vec<u8>);
+/// Galaxies with the number of rows with a galaxy above them, and the number of columns
+/// with a galaxy left of each column
+type Input = (Vec<(Point, u64)>, Vec<u64>);
 
 pub fn solve1(input: Input) -> u64 {
     expand_universe(input, 1)
@@ -24,21 +31,33 @@ fn expand_universe((galaxies, fixed_cols_before): Input, steps: u64) -> Vec<Poin
         .into_iter()
         .map(|((row_index, col_index), fixed_rows_before)| {
             (
-                row_index + steps * (row_index - fixed_rows_before as u64),
-                col_index + steps * (col_index - fixed_cols_before[col_index as usize] as u64),
+                row_index + steps * (row_index - fixed_rows_before),
+                col_index + steps * (col_index - fixed_cols_before[col_index as usize]),
             )
         })
         .collect_vec()
 }
 
-pub fn parse_input(input: &str) -> Input {
+/// Fails on rows that are not as wide as the first one
+pub fn parse_input(input: &str) -> Result<Input, AocError> {
     let mut result = Vec::with_capacity(65);
-    let lines = input.lines().collect_vec();
-
-    let mut fixed_rows = 0_u8;
-    let mut fixed_cols_flag = vec![false; lines.len()];
+    let width = input.lines().next().map_or(0, str::len);
+
+    let mut fixed_rows = 0;
+    let mut fixed_cols_flag = vec![false; width];
+    let mut offset = 0;
+
+    for (row_index, line) in input.trim_end().split_inclusive('\n').enumerate() {
+        let row = line.trim_end_matches(['\r', '\n']);
+        if row.len() != width {
+            return Err(AocError::Parse {
+                day: Day11::DAY,
+                location: Location::new(input, &input[offset..]),
+                message: format!("row is not {} wide", width),
+            });
+        }
+        offset += line.len();
 
-    for (row_index, row) in lines.into_iter().enumerate() {
         let mut has_galaxy = false;
 
         for (col_index, &symbol) in row.as_bytes().into_iter().enumerate() {
@@ -54,28 +73,20 @@ pub fn parse_input(input: &str) -> Input {
         }
     }
 
-    let mut fixed_cols_before = vec![0_u8; fixed_cols_flag.len()];
+    let mut fixed_cols_before = vec![0_u64; fixed_cols_flag.len()];
     for i in 1..fixed_cols_before.len() {
         fixed_cols_before[i] =
             fixed_cols_before[i - 1] + if fixed_cols_flag[i - 1] { 1 } else { 0 };
     }
 
-    (result, fixed_cols_before)
+    Ok((result, fixed_cols_before))
 }
 
 fn manhattan_distance((x1, y1): Point, (x2, y2): Point) -> u64 {
     x1.abs_diff(x2) + y1.abs_diff(y2)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day11::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"...#......
+pub const EXAMPLE_INPUT: &str = r#"...#......
 .......#..
 #.........
 ..........
@@ -87,25 +98,65 @@ mod tests {
 #...#.....
 "#;
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "374"),
+        Example::new(2, EXAMPLE_INPUT, "82000210"),
+    ];
+
+    type Input<'s> = Input;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day11::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+        error::AocError,
+    };
+
+    #[test]
+    fn uneven_rows() {
+        assert!(parse_input("...#\n#...\n").is_ok());
+
+        let error = parse_input("...#\n#..\n....\n").unwrap_err();
+        assert!(matches!(error, AocError::Parse { location, .. } if location.line == 2));
+    }
+
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(EXAMPLE_INPUT)), 374)
+        assert_eq!(solve1(parse_input(EXAMPLE_INPUT).unwrap()), 374)
     }
 
     #[test]
     fn solve_part1() {
-        let input = read_input(11, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(input.as_str())))
+        assert_answer(11, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(EXAMPLE_INPUT)), 82000210)
+        assert_eq!(solve2(parse_input(EXAMPLE_INPUT).unwrap()), 82000210)
     }
 
     #[test]
     fn solve_part2() {
-        let input = read_input(11, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(input.as_str())))
+        assert_answer(11, 2)
     }
 }
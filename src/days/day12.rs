@@ -11,9 +11,15 @@ use winnow::{
     PResult, Parser,
 };
 
-use crate::utils::parse_u32;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_u32},
+};
 
-type Input<'s> = Vec<(&'s [u8], Vec<u8>)>;
+/// Spring conditions of a row together with the sizes of the damaged groups
+type Row<'s> = (&'s [u8], Vec<u8>);
+type Input<'s> = Vec<Row<'s>>;
 type Cache = HashMap<(u8, u8, u8), u64>;
 
 pub fn solve1(input: Input) -> u64 {
@@ -152,8 +158,12 @@ fn memoized_arrangements<'b>(
     }
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<(&'s [u8], Vec<u8>)>> {
-    let mut values: Vec<(&'s [u8], Vec<u8>)> = Vec::with_capacity(1000);
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Input<'s>, AocError> {
+    parse_complete(Day12::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Input<'s>> {
+    let mut values: Input<'s> = Vec::with_capacity(1000);
 
     while let Some(value) = opt(terminated(parse_line, newline)).parse_next(input)? {
         values.push(value);
@@ -162,7 +172,7 @@ pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<(&'s [u8], Vec<u8>)>>
     Ok(values)
 }
 
-fn parse_line<'s>(input: &mut &'s str) -> PResult<(&'s [u8], Vec<u8>)> {
+fn parse_line<'s>(input: &mut &'s str) -> PResult<Row<'s>> {
     let mut values: Vec<u8> = Vec::with_capacity(8);
 
     let vents = take_until0(" ").parse_next(input)?;
@@ -177,15 +187,7 @@ fn parse_line<'s>(input: &mut &'s str) -> PResult<(&'s [u8], Vec<u8>)> {
     Ok((vents.as_bytes(), values))
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day12::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"???.### 1,1,3
+pub const EXAMPLE_INPUT: &str = r#"???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
 ????.#...#... 4,1,1
@@ -193,6 +195,39 @@ mod tests {
 ?###???????? 3,2,1
 "#;
 
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "21"),
+        Example::new(2, EXAMPLE_INPUT, "525152"),
+    ];
+
+    type Input<'s> = Input<'s>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day12::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 21)
@@ -200,8 +235,7 @@ mod tests {
 
     #[test]
     fn solve_part1() {
-        let input = read_input(12, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(12, 1)
     }
 
     #[test]
@@ -211,7 +245,6 @@ mod tests {
 
     #[test]
     fn solve_part2() {
-        let input = read_input(12, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(12, 2)
     }
 }
@@ -1,12 +1,15 @@
-use winnow::{
-    ascii::newline,
-    combinator::{opt, terminated},
-    stream::AsChar,
-    token::take_till,
-    PResult, Parser,
+use winnow::{ascii::newline, combinator::separated, PResult, Parser};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
 };
 
-type Matrix<'s> = Vec<&'s [u8]>;
+type Matrix = Grid<u8>;
 
 pub fn solve1(input: Vec<Matrix>) -> usize {
     solve(input, 0)
@@ -17,35 +20,20 @@ pub fn solve2(input: Vec<Matrix>) -> usize {
 }
 
 fn solve(input: Vec<Matrix>, delta: u32) -> usize {
-    let mut col_values = vec![0_u32; 32];
-    let mut row_values = vec![0_u32; 32];
-
     input
         .into_iter()
         .map(|matrix| {
-            col_values.fill(0_u32);
-            row_values.fill(0_u32);
-
-            let cols = matrix[0].len();
-            let rows = matrix.len();
-
-            for row_index in 0..rows {
-                for col_index in 0..cols {
-                    let v = if matrix[row_index][col_index] == b'.' { 0 } else { 1 };
-
-                    row_values[row_index] = row_values[row_index] << 1 | v;
-                    col_values[col_index] = col_values[col_index] << 1 | v;
-                }
-            }
+            let row_values: Vec<u32> = matrix.rows().map(|row| hash(row.iter())).collect();
+            let col_values: Vec<u32> = matrix.columns().map(hash).collect();
 
-            for i in 1..rows {
-                if mirrored_delta(&row_values, i, rows) == delta {
+            for i in 1..row_values.len() {
+                if mirrored_delta(&row_values, i) == delta {
                     return i * 100;
                 }
             }
 
-            for i in 1..cols {
-                if mirrored_delta(&col_values, i, cols) == delta {
+            for i in 1..col_values.len() {
+                if mirrored_delta(&col_values, i) == delta {
                     return i;
                 }
             }
@@ -55,50 +43,28 @@ fn solve(input: Vec<Matrix>, delta: u32) -> usize {
         .sum()
 }
 
-fn mirrored_delta(hashes: &[u32], offset: usize, hashes_len: usize) -> u32 {
+/// One bit per cell of a row or column, set for rocks
+fn hash<'a>(cells: impl Iterator<Item = &'a u8>) -> u32 {
+    cells.fold(0, |value, &cell| value << 1 | (cell != b'.') as u32)
+}
+
+fn mirrored_delta(hashes: &[u32], offset: usize) -> u32 {
     (0..offset)
         .rev()
-        .zip(offset..hashes_len)
+        .zip(offset..hashes.len())
         .map(|(left, right)| (hashes[left] ^ hashes[right]).count_ones())
         .sum::<u32>()
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Vec<Matrix<'s>>> {
-    let mut values: Vec<Matrix> = Vec::with_capacity(100);
-
-    while let Some(value) = opt(terminated(parse_matrix, newline)).parse_next(input)? {
-        values.push(value);
-    }
-
-    values.push(parse_matrix(input)?);
-
-    Ok(values)
+pub fn parse_input(input: &mut &str) -> Result<Vec<Matrix>, AocError> {
+    parse_complete(Day13::DAY, input, parse_puzzle)
 }
 
-pub fn parse_matrix<'s>(input: &mut &'s str) -> PResult<Matrix<'s>> {
-    let mut values: Vec<&'s [u8]> = Vec::with_capacity(32);
-
-    while let Some(value) = opt(terminated(
-        take_till(1.., |c: char| c.is_newline()),
-        newline,
-    ))
-    .parse_next(input)?
-    {
-        values.push(value.as_bytes());
-    }
-
-    Ok(values)
+fn parse_puzzle(input: &mut &str) -> PResult<Vec<Matrix>> {
+    separated(1.., parse_grid, (newline, newline)).parse_next(input)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day13::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"#.##..##.
+pub const EXAMPLE_INPUT: &str = r#"#.##..##.
 ..#.##.#.
 ##......#
 ##......#
@@ -115,6 +81,39 @@ mod tests {
 #....#..#
 "#;
 
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "405"),
+        Example::new(2, EXAMPLE_INPUT, "400"),
+    ];
+
+    type Input<'s> = Vec<Matrix>;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day13::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 405)
@@ -122,8 +121,7 @@ mod tests {
 
     #[test]
     fn solve_part1() {
-        let input = read_input(13, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(13, 1)
     }
 
     #[test]
@@ -133,8 +131,6 @@ mod tests {
 
     #[test]
     fn solve_part2() {
-        let input = read_input(13, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(13, 2)
     }
-
 }
@@ -1,59 +1,40 @@
 use hashbrown::HashMap;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
-use winnow::{
-    ascii::newline,
-    combinator::{opt, terminated},
-    stream::AsChar,
-    token::take_till,
-    PResult, Parser,
-};
 
-type Matrix = Vec<Vec<u8>>;
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        geom::{Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
 
-#[derive(Debug)]
-enum Direction {
-    North,
-    West,
-    South,
-    East,
-}
+type Matrix = Grid<u8>;
 
 const ROUND_ROCK: u8 = b'O';
 const CUBE_ROCK: u8 = b'#';
 const EMPTY_SPACE: u8 = b'.';
 
 pub fn solve1(mut input: Matrix) -> usize {
-    let rows = input.len();
-
-    move_rocks(&mut input, Direction::North);
+    tilt(&mut input, Direction::Up);
 
-    input
-        .into_iter()
-        .enumerate()
-        .map(|(row_index, line)| {
-            let rocks = line.into_iter().filter(|&c| c == ROUND_ROCK).count();
-            rocks * (rows - row_index)
-        })
-        .sum()
+    north_load(&input)
 }
 
 pub fn solve2(mut input: Matrix) -> usize {
     const TARGET_CYCLE: usize = 1_000_000_000;
 
-    let rows = input.len();
-
     let mut seen = HashMap::with_capacity(164);
     let mut cycle = 1;
     let mut cycle_length = 0;
 
     while cycle < TARGET_CYCLE {
-        move_rocks(&mut input, Direction::North);
-        move_rocks(&mut input, Direction::West);
-        move_rocks(&mut input, Direction::South);
-        move_rocks(&mut input, Direction::East);
+        spin_cycle(&mut input);
 
-        let hash = calculate_hash(&mut input);
+        let hash = calculate_hash(&input);
         if let Some(&length) = seen.get(&hash) {
             cycle_length = cycle - length;
             break;
@@ -65,116 +46,71 @@ pub fn solve2(mut input: Matrix) -> usize {
 
     let remaining = (TARGET_CYCLE - cycle) % cycle_length;
     for _ in 0..remaining {
-        move_rocks(&mut input, Direction::North);
-        move_rocks(&mut input, Direction::West);
-        move_rocks(&mut input, Direction::South);
-        move_rocks(&mut input, Direction::East);
+        spin_cycle(&mut input);
     }
 
+    north_load(&input)
+}
+
+fn north_load(input: &Matrix) -> usize {
     input
-        .into_iter()
+        .rows()
         .enumerate()
         .map(|(row_index, line)| {
-            let rocks = line.into_iter().filter(|&c| c == ROUND_ROCK).count();
-            rocks * (rows - row_index)
+            let rocks = line.iter().filter(|&&c| c == ROUND_ROCK).count();
+            rocks * (input.height() - row_index)
         })
         .sum()
 }
 
-fn move_rocks(input: &mut Matrix, direction: Direction) {
-    let rows = input.len();
-    let cols = input[0].len();
-
-    match direction {
-        Direction::North => {
-            for col_index in 0..cols {
-                let mut top_index = 0;
-
-                for row_index in 0..rows {
-                    match input[row_index][col_index] {
-                        ROUND_ROCK if top_index == row_index => {
-                            top_index += 1;
-                        }
-                        ROUND_ROCK => {
-                            input[top_index][col_index] = ROUND_ROCK;
-                            input[row_index][col_index] = EMPTY_SPACE;
-                            top_index += 1;
-                        }
-                        CUBE_ROCK => {
-                            top_index = row_index + 1;
-                        }
-                        _ => { /* nothing */ }
-                    }
-                }
-            }
-        }
+/// Tilts north, west, south and east
+fn spin_cycle(input: &mut Matrix) {
+    for direction in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        tilt(input, direction);
+    }
+}
 
-        Direction::South => {
-            for col_index in 0..cols {
-                let mut top_index = rows - 1;
-
-                for row_index in (0..rows).rev() {
-                    match input[row_index][col_index] {
-                        ROUND_ROCK if top_index == row_index => {
-                            top_index = top_index.checked_sub(1).unwrap_or(0);
-                        }
-                        ROUND_ROCK => {
-                            input[top_index][col_index] = ROUND_ROCK;
-                            input[row_index][col_index] = EMPTY_SPACE;
-                            top_index -= 1;
-                        }
-                        CUBE_ROCK if row_index > 0 => {
-                            top_index = row_index - 1;
-                        }
-                        _ => { /* nothing */ }
-                    }
-                }
-            }
-        }
+/// Rolls all round rocks towards `direction` until they hit a cube rock or the edge
+fn tilt(input: &mut Matrix, direction: Direction) {
+    let (lanes, length) = match direction {
+        Direction::Up | Direction::Down => (input.width(), input.height()),
+        Direction::Left | Direction::Right => (input.height(), input.width()),
+    };
 
-        Direction::West => {
-            for row_index in 0..rows {
-                let mut top_index = 0;
-
-                for col_index in 0..cols {
-                    match input[row_index][col_index] {
-                        ROUND_ROCK if top_index == col_index => {
-                            top_index += 1;
-                        }
-                        ROUND_ROCK => {
-                            input[row_index][top_index] = ROUND_ROCK;
-                            input[row_index][col_index] = EMPTY_SPACE;
-                            top_index += 1;
-                        }
-                        CUBE_ROCK => {
-                            top_index = col_index + 1;
-                        }
-                        _ => { /* nothing */ }
-                    }
-                }
+    for lane in 0..lanes {
+        // Index 0 is the edge the rocks roll towards
+        let position = |index: usize| {
+            let index = match direction {
+                Direction::Up | Direction::Left => index,
+                Direction::Down | Direction::Right => length - 1 - index,
+            };
+
+            match direction {
+                Direction::Up | Direction::Down => Point::new(index, lane),
+                Direction::Left | Direction::Right => Point::new(lane, index),
             }
-        }
+        };
 
-        Direction::East => {
-            for row_index in 0..rows {
-                let mut top_index = rows - 1;
-
-                for col_index in (0..cols).rev() {
-                    match input[row_index][col_index] {
-                        ROUND_ROCK if top_index == col_index => {
-                            top_index = top_index.checked_sub(1).unwrap_or(0);
-                        }
-                        ROUND_ROCK => {
-                            input[row_index][top_index] = ROUND_ROCK;
-                            input[row_index][col_index] = EMPTY_SPACE;
-                            top_index -= 1;
-                        }
-                        CUBE_ROCK if col_index > 0 => {
-                            top_index = col_index - 1;
-                        }
-                        _ => { /* nothing */ }
-                    }
+        let mut top_index = 0;
+        for index in 0..length {
+            match input[position(index)] {
+                ROUND_ROCK if top_index == index => {
+                    top_index += 1;
+                }
+                ROUND_ROCK => {
+                    input[position(top_index)] = ROUND_ROCK;
+                    input[position(index)] = EMPTY_SPACE;
+                    top_index += 1;
                 }
+                CUBE_ROCK => {
+                    top_index = index + 1;
+                }
+                _ => { /* nothing */ }
             }
         }
     }
@@ -187,30 +123,11 @@ fn calculate_hash<T: Hash>(t: &T) -> u64 {
     s.finish()
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Matrix> {
-    let mut values: Vec<Vec<u8>> = Vec::with_capacity(100);
-
-    while let Some(value) = opt(terminated(
-        take_till(1.., |c: char| c.is_newline()),
-        newline,
-    ))
-    .parse_next(input)?
-    {
-        values.push(value.into());
-    }
-
-    Ok(values)
+pub fn parse_input(input: &mut &str) -> Result<Matrix, AocError> {
+    parse_complete(Day14::DAY, input, parse_grid)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day14::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"O....#....
+pub const EXAMPLE_INPUT: &str = r#"O....#....
 O.OO#....#
 .....##...
 OO.#O....O
@@ -222,6 +139,52 @@ O.#..O.#.#
 #OO..#....
 "#;
 
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "136"),
+        Example::new(2, EXAMPLE_INPUT, "64"),
+    ];
+
+    type Input<'s> = Matrix;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day14::{parse_input, solve1, solve2, spin_cycle, EXAMPLE_INPUT},
+    };
+
+    #[test]
+    fn spin() {
+        let mut platform = parse_input(&mut EXAMPLE_INPUT).unwrap();
+        spin_cycle(&mut platform);
+
+        let expected = parse_input(
+            &mut ".....#....\n....#...O#\n...OO##...\n.OO#......\n.....OOO#.\n\
+                  .O#...O#.#\n....O#....\n......OOOO\n#...O###..\n#..OO#....\n",
+        )
+        .unwrap();
+        assert_eq!(platform, expected);
+    }
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 136)
@@ -229,8 +192,7 @@ O.#..O.#.#
 
     #[test]
     fn solve_part1() {
-        let input = read_input(14, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(14, 1)
     }
 
     #[test]
@@ -240,7 +202,6 @@ O.#..O.#.#
 
     #[test]
     fn solve_part2() {
-        let input = read_input(14, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(14, 2)
     }
 }
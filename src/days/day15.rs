@@ -2,6 +2,11 @@ use std::collections::VecDeque;
 
 use itertools::Itertools;
 
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+};
+
 pub fn solve1(input: &str) -> usize {
     let mut result = 0_usize;
     let mut current_iteration = 0_u32;
@@ -89,39 +94,62 @@ pub fn solve2<'s>(input: &'s str) -> usize {
 }
 
 #[inline]
-pub fn parse_input(input: &str) -> &str {
-    input
+pub fn parse_input(input: &str) -> Result<&str, AocError> {
+    Ok(input)
+}
+
+pub const EXAMPLE_INPUT: &str = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "1320"),
+        Example::new(2, EXAMPLE_INPUT, "145"),
+    ];
+
+    type Input<'s> = &'s str;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
 }
 
 #[allow(const_item_mutation)]
 #[cfg(test)]
 mod tests {
     use crate::{
-        day15::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
+        answers::assert_answer,
+        day15::{parse_input, solve1, solve2, EXAMPLE_INPUT},
     };
 
-    const EXAMPLE_INPUT: &'static str = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7"#;
-
     #[test]
     fn part1() {
-        assert_eq!(solve1(parse_input(EXAMPLE_INPUT)), 1320)
+        assert_eq!(solve1(parse_input(EXAMPLE_INPUT).unwrap()), 1320)
     }
 
     #[test]
     fn solve_part1() {
-        let input = read_input(15, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(input.as_str())))
+        assert_answer(15, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT)), 145)
+        assert_eq!(solve2(parse_input(EXAMPLE_INPUT).unwrap()), 145)
     }
 
     #[test]
     fn solve_part2() {
-        let input = read_input(15, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(input.as_str())))
+        assert_answer(15, 2)
     }
 }
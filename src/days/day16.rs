@@ -1,60 +1,51 @@
 use rayon::iter::ParallelBridge;
 use rayon::prelude::ParallelIterator;
 use std::collections::VecDeque;
-use winnow::{
-    ascii::newline,
-    combinator::{opt, terminated},
-    stream::AsChar,
-    token::take_till,
-    PResult, Parser,
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        geom::{Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
 };
 
-type Matrix = Vec<Vec<u8>>;
+type Matrix = Grid<u8>;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
+/// A beam entering `position` while travelling in `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Beam {
+    pub position: Point<usize>,
+    pub direction: Direction,
 }
 
-impl Direction {
-    pub fn next(&self, row: usize, col: usize) -> (i64, i64) {
-        match self {
-            Direction::Up => (row as i64 - 1, col as i64),
-            Direction::Left => (row as i64, col as i64 - 1),
-            Direction::Down => (row as i64 + 1, col as i64),
-            Direction::Right => (row as i64, col as i64 + 1),
-        }
-    }
-
-    pub fn bit_mask(&self) -> u8 {
-        match self {
-            Direction::Up => 0b00000001,
-            Direction::Left => 0b00000010,
-            Direction::Down => 0b00000100,
-            Direction::Right => 0b00001000,
+impl Beam {
+    fn new(row: usize, col: usize, direction: Direction) -> Beam {
+        Beam {
+            position: Point::new(row, col),
+            direction,
         }
     }
 }
 
 pub fn solve1(input: Matrix) -> usize {
-    simulate_beam(&input, (0, 0, Direction::Right))
+    simulate_beam(&input, Beam::new(0, 0, Direction::Right))
 }
 
 pub fn solve2(input: Matrix) -> usize {
-    let rows = input.len();
-    let cols = input[0].len();
+    let rows = input.height();
+    let cols = input.width();
 
     (0..rows)
-        .map(|row| (row, 0, Direction::Right))
-        .chain((0..rows).map(|row| (row, cols - 1, Direction::Left)))
-        .chain((0..cols).map(|col| (0, col, Direction::Down)))
-        .chain((0..cols).map(|col| (rows - 1, col, Direction::Up)))
+        .map(|row| Beam::new(row, 0, Direction::Right))
+        .chain((0..rows).map(|row| Beam::new(row, cols - 1, Direction::Left)))
+        .chain((0..cols).map(|col| Beam::new(0, col, Direction::Down)))
+        .chain((0..cols).map(|col| Beam::new(rows - 1, col, Direction::Up)))
         .par_bridge()
         .map_with(new_visited(&input), |cache, beam| {
-            clear_visited(cache);
+            cache.fill(0_u8);
             _simulate_beam(&input, beam, cache)
         })
         .max()
@@ -62,131 +53,61 @@ pub fn solve2(input: Matrix) -> usize {
 }
 
 #[inline]
-fn simulate_beam(input: &Matrix, beam: (usize, usize, Direction)) -> usize {
+fn simulate_beam(input: &Matrix, beam: Beam) -> usize {
     _simulate_beam(input, beam, &mut new_visited(input))
 }
 
 #[inline]
-fn new_visited(input: &Matrix) -> Vec<Vec<u8>> {
-    vec![vec![0_u8; input[0].len()]; input.len()]
-}
-
-#[inline]
-fn clear_visited(visited: &mut Vec<Vec<u8>>) {
-    for row in visited {
-        row.fill(0_u8);
-    }
+fn new_visited(input: &Matrix) -> Grid<u8> {
+    Grid::filled(input.width(), input.height(), 0_u8)
 }
 
-fn _simulate_beam(
-    input: &Matrix,
-    beam: (usize, usize, Direction),
-    visited: &mut Vec<Vec<u8>>,
-) -> usize {
+fn _simulate_beam(input: &Matrix, beam: Beam, visited: &mut Grid<u8>) -> usize {
     let mut beams = VecDeque::with_capacity(32);
-
-    let rows = input.len();
-    let cols = input[0].len();
-
-    let is_in_bounds = move |(row, col): (i64, i64)| {
-        row >= 0 && col >= 0 && row < rows as i64 && col < cols as i64
-    };
-
-    let enqueue_beam = move |beams: &mut VecDeque<(usize, usize, Direction)>,
-                             point @ (row, col): (i64, i64),
-                             direction: Direction| {
-        if is_in_bounds(point) {
-            beams.push_back((row as usize, col as usize, direction));
-        }
-    };
-
     beams.push_back(beam);
 
-    while let Some((row, col, direction)) = beams.pop_front() {
-        if visited[row][col] & direction.bit_mask() > 0 {
+    while let Some(Beam {
+        position,
+        direction,
+    }) = beams.pop_front()
+    {
+        if visited[position] & direction.bit_mask() > 0 {
             continue;
         }
 
-        visited[row][col] |= direction.bit_mask();
-
-        match input[row][col] {
-            b'.' => enqueue_beam(&mut beams, direction.next(row, col), direction),
-            b'\\' => match direction {
-                Direction::Up => {
-                    enqueue_beam(&mut beams, (row as i64, col as i64 - 1), Direction::Left)
-                }
-                Direction::Left => {
-                    enqueue_beam(&mut beams, (row as i64 - 1, col as i64), Direction::Up)
-                }
-                Direction::Down => {
-                    enqueue_beam(&mut beams, (row as i64, col as i64 + 1), Direction::Right)
-                }
-                Direction::Right => {
-                    enqueue_beam(&mut beams, (row as i64 + 1, col as i64), Direction::Down)
-                }
-            },
-            b'/' => match direction {
-                Direction::Up => {
-                    enqueue_beam(&mut beams, (row as i64, col as i64 + 1), Direction::Right)
-                }
-                Direction::Left => {
-                    enqueue_beam(&mut beams, (row as i64 + 1, col as i64), Direction::Down)
-                }
-                Direction::Down => {
-                    enqueue_beam(&mut beams, (row as i64, col as i64 - 1), Direction::Left)
-                }
-                Direction::Right => {
-                    enqueue_beam(&mut beams, (row as i64 - 1, col as i64), Direction::Up)
-                }
-            },
-            b'|' if direction == Direction::Up || direction == Direction::Down => {
-                enqueue_beam(&mut beams, direction.next(row, col), direction)
-            }
-            b'|' => {
-                enqueue_beam(&mut beams, (row as i64 - 1, col as i64), Direction::Up);
-                enqueue_beam(&mut beams, (row as i64 + 1, col as i64), Direction::Down);
-            }
-            b'-' if direction == Direction::Right || direction == Direction::Left => {
-                enqueue_beam(&mut beams, direction.next(row, col), direction)
-            }
-            b'-' => {
-                enqueue_beam(&mut beams, (row as i64, col as i64 - 1), Direction::Left);
-                enqueue_beam(&mut beams, (row as i64, col as i64 + 1), Direction::Right);
-            }
+        visited[position] |= direction.bit_mask();
+
+        let outgoing: &[Direction] = match (input[position], direction) {
+            (b'.', _) => &[direction],
+            (b'\\', Direction::Up) | (b'/', Direction::Down) => &[Direction::Left],
+            (b'\\', Direction::Left) | (b'/', Direction::Right) => &[Direction::Up],
+            (b'\\', Direction::Down) | (b'/', Direction::Up) => &[Direction::Right],
+            (b'\\', Direction::Right) | (b'/', Direction::Left) => &[Direction::Down],
+            (b'|', Direction::Up | Direction::Down) => &[direction],
+            (b'|', _) => &[Direction::Up, Direction::Down],
+            (b'-', Direction::Left | Direction::Right) => &[direction],
+            (b'-', _) => &[Direction::Left, Direction::Right],
             _ => unreachable!(),
+        };
+
+        for &next in outgoing {
+            if let Some(next_position) = input.step(position, next) {
+                beams.push_back(Beam {
+                    position: next_position,
+                    direction: next,
+                });
+            }
         }
     }
 
-    visited
-        .into_iter()
-        .map(|line| line.into_iter().filter(|&&mut p| p > 0).count())
-        .sum()
+    visited.cells().iter().filter(|&&p| p > 0).count()
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Matrix> {
-    let mut values: Vec<Vec<u8>> = Vec::with_capacity(100);
-
-    while let Some(value) = opt(terminated(
-        take_till(1.., |c: char| c.is_newline()),
-        newline,
-    ))
-    .parse_next(input)?
-    {
-        values.push(value.into());
-    }
-
-    Ok(values)
+pub fn parse_input(input: &mut &str) -> Result<Matrix, AocError> {
+    parse_complete(Day16::DAY, input, parse_grid)
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day16::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#".|...\....
+pub const EXAMPLE_INPUT: &str = r#".|...\....
 |.-.\.....
 .....|-...
 ........|.
@@ -198,6 +119,39 @@ mod tests {
 ..//.|....
 "#;
 
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "46"),
+        Example::new(2, EXAMPLE_INPUT, "51"),
+    ];
+
+    type Input<'s> = Matrix;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day16::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 46)
@@ -205,8 +159,7 @@ mod tests {
 
     #[test]
     fn solve_part1() {
-        let input = read_input(16, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(16, 1)
     }
 
     #[test]
@@ -216,7 +169,6 @@ mod tests {
 
     #[test]
     fn solve_part2() {
-        let input = read_input(16, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(16, 2)
     }
 }
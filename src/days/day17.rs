@@ -1,17 +1,28 @@
-use itertools::Itertools;
+use pathfinding::directed::dijkstra::dijkstra;
 use winnow::{
-    ascii::newline,
-    combinator::{opt, terminated},
-    stream::AsChar,
-    token::take_till,
-    PResult, Parser,
+    error::{ErrMode, ErrorKind, ParserError},
+    PResult,
 };
 
-type Matrix = Vec<Vec<u8>>;
-type Direction = (i8, i8);
-type Point = (u8, u8);
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        geom::{Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
 
-use pathfinding::directed::dijkstra::dijkstra;
+type Matrix = Grid<u8>;
+
+/// Position, direction of the last move and how many moves went straight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crucible {
+    pub position: Point<usize>,
+    pub direction: Option<Direction>,
+    pub straight: u8,
+}
 
 pub fn solve1(input: Matrix) -> usize {
     shortest_path(input, 0, 3)
@@ -22,43 +33,42 @@ pub fn solve2(input: Matrix) -> usize {
 }
 
 fn shortest_path(input: Matrix, min_length: u8, max_length: u8) -> usize {
-    let rows = input.len() as u8;
-    let cols = input[0].len() as u8;
-
-    let start: Point = (0, 0);
-    let initial_direction: Direction = (0, 0);
-    let end: Point = (rows - 1, cols - 1);
+    let start = Crucible {
+        position: Point::new(0, 0),
+        direction: None,
+        straight: 0,
+    };
+    let end = Point::new(input.height() - 1, input.width() - 1);
 
     dijkstra(
-        &(start, initial_direction, 0),
-        |&(point, dir @ (dir_row, dir_col), path_length)| {
+        &start,
+        |&Crucible {
+             position,
+             direction,
+             straight,
+         }| {
             let mut next = Vec::with_capacity(3);
 
-            if path_length < max_length {
-                if let Some(n) = get_neighbor(&input, point, dir, path_length + 1) {
-                    next.push(n);
-                }
-            }
-
-            if path_length == 0 {
-                if let Some(n) = get_neighbor(&input, point, (1, 0), 1) {
-                    next.push(n);
-                }
-                if let Some(n) = get_neighbor(&input, point, (0, 1), 1) {
-                    next.push(n);
+            match direction {
+                None => {
+                    next.extend(get_neighbor(&input, position, Direction::Down, 1));
+                    next.extend(get_neighbor(&input, position, Direction::Right, 1));
                 }
-            } else if path_length >= min_length {
-                if let Some(n) = get_neighbor(&input, point, (-dir_col, -dir_row), 1) {
-                    next.push(n);
+                Some(direction) => {
+                    if straight < max_length {
+                        next.extend(get_neighbor(&input, position, direction, straight + 1));
+                    }
+
+                    if straight >= min_length {
+                        next.extend(get_neighbor(&input, position, direction.turn_left(), 1));
+                        next.extend(get_neighbor(&input, position, direction.turn_right(), 1));
+                    }
                 }
-                if let Some(n) = get_neighbor(&input, point, (dir_col, dir_row), 1) {
-                    next.push(n);
-                }
-            } 
+            }
 
             next
         },
-        |&(pos, _, l)| pos == end && l >= min_length,
+        |crucible| crucible.position == end && crucible.straight >= min_length,
     )
     .unwrap()
     .1
@@ -66,56 +76,35 @@ fn shortest_path(input: Matrix, min_length: u8, max_length: u8) -> usize {
 
 fn get_neighbor(
     input: &Matrix,
-    (row, col): Point,
-    direction @ (dir_row, dir_col): Direction,
+    point: Point<usize>,
+    direction: Direction,
     length: u8,
-) -> Option<((Point, Direction, u8), usize)> {
-    let rows = input.len() as i16;
-    let cols = input[0].len() as i16;
-
-    let row = row as i16 + dir_row as i16;
-    let col = col as i16 + dir_col as i16;
-
-    if row >= 0 && row < rows && col >= 0 && col < cols {
-        Some((
-            ((row as u8, col as u8), direction, length),
-            input[row as usize][col as usize] as usize,
-        ))
-    } else {
-        None
-    }
+) -> Option<(Crucible, usize)> {
+    let next = input.step(point, direction)?;
+    let crucible = Crucible {
+        position: next,
+        direction: Some(direction),
+        straight: length,
+    };
+
+    Some((crucible, input[next] as usize))
 }
 
-pub fn parse_input<'s>(input: &mut &'s str) -> PResult<Matrix> {
-    let mut values: Vec<Vec<u8>> = Vec::with_capacity(142);
-
-    while let Some(value) = opt(terminated(
-        take_till(1.., |c: char| c.is_newline()),
-        newline,
-    ))
-    .parse_next(input)?
-    {
-        values.push(
-            value
-                .as_bytes()
-                .into_iter()
-                .map(|&c| c - b'0')
-                .collect_vec(),
-        );
+pub fn parse_input(input: &mut &str) -> Result<Matrix, AocError> {
+    parse_complete(Day17::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Matrix> {
+    let grid = parse_grid(input)?;
+
+    if !grid.cells().iter().all(u8::is_ascii_digit) {
+        return Err(ErrMode::from_error_kind(input, ErrorKind::Verify));
     }
 
-    Ok(values)
+    Ok(grid.map(|&c| c - b'0'))
 }
 
-#[allow(const_item_mutation)]
-#[cfg(test)]
-mod tests {
-    use crate::{
-        day17::{parse_input, solve1, solve2},
-        utils::{read_input, Part},
-    };
-
-    const EXAMPLE_INPUT: &'static str = r#"2413432311323
+pub const EXAMPLE_INPUT: &str = r#"2413432311323
 3215453535623
 3255245654254
 3446585845452
@@ -130,6 +119,39 @@ mod tests {
 4322674655533
 "#;
 
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "102"),
+        Example::new(2, EXAMPLE_INPUT, "94"),
+    ];
+
+    type Input<'s> = Matrix;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day17::{parse_input, solve1, solve2, EXAMPLE_INPUT},
+    };
+
     #[test]
     fn part1() {
         assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 102)
@@ -137,18 +159,16 @@ mod tests {
 
     #[test]
     fn solve_part1() {
-        let input = read_input(17, Part::Part1).expect("unable to read input file");
-        println!("{}", solve1(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(17, 1)
     }
 
     #[test]
     fn part2() {
-        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 51)
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 94)
     }
 
     #[test]
     fn solve_part2() {
-        let input = read_input(17, Part::Part1).expect("unable to read input file");
-        println!("{}", solve2(parse_input(&mut input.as_str()).unwrap()))
+        assert_answer(17, 2)
     }
 }
@@ -0,0 +1,214 @@
+use itertools::Itertools;
+use winnow::{
+    ascii::{newline, space1},
+    combinator::{alt, cut_err, delimited, preceded, separated},
+    token::take,
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{
+        geom::{interior_points, Direction, Point},
+        parse_complete, parse_u32,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub distance: u32,
+    /// The hex code of the trench color, part 2 reads the real instruction from it
+    pub color: u32,
+}
+
+impl Instruction {
+    /// The first five hex digits are the distance, the last one the direction.
+    /// `None` if the direction digit is not in `0..=3`, the parser rejects such colors.
+    pub fn decode(&self) -> Option<(Direction, i64)> {
+        Some((color_direction(self.color)?, (self.color >> 4) as i64))
+    }
+}
+
+fn color_direction(color: u32) -> Option<Direction> {
+    match color & 0xF {
+        0 => Some(Direction::Right),
+        1 => Some(Direction::Down),
+        2 => Some(Direction::Left),
+        3 => Some(Direction::Up),
+        _ => None,
+    }
+}
+
+pub fn solve1(input: Vec<Instruction>) -> u64 {
+    lagoon_volume(
+        input
+            .iter()
+            .map(|instruction| (instruction.direction, instruction.distance as i64)),
+    )
+}
+
+pub fn solve2(input: Vec<Instruction>) -> u64 {
+    lagoon_volume(input.iter().map(|instruction| {
+        instruction
+            .decode()
+            .expect("colors are checked while parsing")
+    }))
+}
+
+/// Cubic meters of lava the lagoon holds, including the trench itself
+pub fn lagoon_volume(plan: impl Iterator<Item = (Direction, i64)>) -> u64 {
+    let mut position = Point::default();
+    let mut boundary = 0;
+
+    let vertices = plan
+        .map(|(direction, distance)| {
+            position = position + direction.unit() * distance;
+            boundary += distance;
+
+            position
+        })
+        .collect_vec();
+
+    (interior_points(&vertices, boundary) + boundary) as u64
+}
+
+pub fn parse_input(input: &mut &str) -> Result<Vec<Instruction>, AocError> {
+    parse_complete(Day18::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Vec<Instruction>> {
+    separated(1.., parse_instruction, newline).parse_next(input)
+}
+
+fn parse_instruction(input: &mut &str) -> PResult<Instruction> {
+    let direction = alt((
+        'U'.value(Direction::Up),
+        'D'.value(Direction::Down),
+        'L'.value(Direction::Left),
+        'R'.value(Direction::Right),
+    ))
+    .parse_next(input)?;
+
+    let distance = preceded(space1, parse_u32).parse_next(input)?;
+
+    let color = delimited(
+        " (#",
+        cut_err(
+            take(6_usize)
+                .try_map(|hex| u32::from_str_radix(hex, 16))
+                .verify(|&color| color_direction(color).is_some()),
+        ),
+        ')',
+    )
+    .parse_next(input)?;
+
+    Ok(Instruction {
+        direction,
+        distance,
+        color,
+    })
+}
+
+pub const EXAMPLE_INPUT: &str = r#"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+"#;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "62"),
+        Example::new(2, EXAMPLE_INPUT, "952408144115"),
+    ];
+
+    type Input<'s> = Vec<Instruction>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day18::{parse_input, solve1, solve2, Instruction, EXAMPLE_INPUT},
+        error::AocError,
+        utils::geom::Direction,
+    };
+
+    #[test]
+    fn input_parsing() {
+        let instructions = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(instructions.len(), 14);
+        assert_eq!(
+            instructions[0],
+            Instruction {
+                direction: Direction::Right,
+                distance: 6,
+                color: 0x70c710,
+            }
+        );
+        assert_eq!(instructions[0].decode(), Some((Direction::Right, 461937)));
+        assert_eq!(instructions[1].decode(), Some((Direction::Down, 56407)));
+    }
+
+    #[test]
+    fn invalid_color() {
+        let error = parse_input(&mut "R 6 (#70c710)\nD 5 (#0dc574)\n").unwrap_err();
+
+        assert!(matches!(
+            error,
+            AocError::Parse { location, .. } if (location.line, location.column) == (2, 7)
+        ));
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 62)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(18, 1)
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()),
+            952408144115
+        )
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(18, 2)
+    }
+}
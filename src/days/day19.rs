@@ -0,0 +1,419 @@
+use hashbrown::HashMap;
+use winnow::{
+    ascii::{alpha1, newline},
+    combinator::{alt, delimited, preceded, repeat, separated, separated_pair, terminated},
+    error::{ErrMode, ErrorKind, ParserError},
+    token::one_of,
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{interval::Interval, parse_complete, parse_u16},
+};
+
+type WorkflowId = u16;
+
+/// Workflow before the names of its targets are resolved
+type NamedWorkflow<'s> = (&'s str, Vec<(Condition, &'s str)>, &'s str);
+
+/// Ratings of a part in the order x, m, a, s
+pub type Rating = [u16; 4];
+
+/// Rating ranges of many parts in the order x, m, a, s
+pub type RatingRanges = [Interval<u16>; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(WorkflowId),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Less(usize, u16),
+    Greater(usize, u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub condition: Condition,
+    pub target: Target,
+}
+
+/// The rules are checked in order, parts matching none of them go to `fallback`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow {
+    pub rules: Vec<Rule>,
+    pub fallback: Target,
+}
+
+/// No loop of workflows can be reached from `in`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    /// Workflows indexed by their [`WorkflowId`], `in` is the first one
+    pub workflows: Vec<Workflow>,
+    pub ratings: Vec<Rating>,
+}
+
+const START: WorkflowId = 0;
+
+impl Condition {
+    pub fn matches(&self, rating: &Rating) -> bool {
+        match *self {
+            Condition::Less(category, value) => rating[category] < value,
+            Condition::Greater(category, value) => rating[category] > value,
+        }
+    }
+
+    /// Splits the ranges into the matching and the remaining ones
+    pub fn split(&self, ranges: &RatingRanges) -> (Option<RatingRanges>, Option<RatingRanges>) {
+        let (category, (matching, remaining)) = match *self {
+            Condition::Less(category, value) => (category, ranges[category].split_at(value)),
+            Condition::Greater(category, value) => match value.checked_add(1) {
+                Some(bound) => {
+                    let (remaining, matching) = ranges[category].split_at(bound);
+                    (category, (matching, remaining))
+                }
+                None => return (None, Some(*ranges)),
+            },
+        };
+
+        let replace = |interval: Interval<u16>| {
+            let mut ranges = *ranges;
+            ranges[category] = interval;
+            ranges
+        };
+
+        (matching.map(replace), remaining.map(replace))
+    }
+}
+
+pub fn solve1(input: Input) -> u64 {
+    input
+        .ratings
+        .iter()
+        .filter(|rating| is_accepted(&input.workflows, rating))
+        .map(|rating| rating.iter().map(|&value| value as u64).sum::<u64>())
+        .sum()
+}
+
+pub fn solve2(input: Input) -> u64 {
+    let ranges = [Interval::new(1, 4001); 4];
+
+    count_accepted(&input.workflows, START, ranges)
+}
+
+impl Workflow {
+    pub fn target(&self, rating: &Rating) -> Target {
+        self.rules
+            .iter()
+            .find(|rule| rule.condition.matches(rating))
+            .map_or(self.fallback, |rule| rule.target)
+    }
+
+    fn targets(&self) -> impl Iterator<Item = Target> + '_ {
+        self.rules
+            .iter()
+            .map(|rule| rule.target)
+            .chain([self.fallback])
+    }
+}
+
+pub fn is_accepted(workflows: &[Workflow], rating: &Rating) -> bool {
+    let mut workflow = START;
+
+    loop {
+        match workflows[workflow as usize].target(rating) {
+            Target::Accept => return true,
+            Target::Reject => return false,
+            Target::Workflow(next) => workflow = next,
+        }
+    }
+}
+
+/// Number of rating combinations within `ranges` that get accepted starting at `workflow`
+pub fn count_accepted(workflows: &[Workflow], workflow: WorkflowId, ranges: RatingRanges) -> u64 {
+    let count = |target: Target, ranges: RatingRanges| match target {
+        Target::Accept => ranges.iter().map(|range| range.len() as u64).product(),
+        Target::Reject => 0,
+        Target::Workflow(next) => count_accepted(workflows, next, ranges),
+    };
+
+    let workflow = &workflows[workflow as usize];
+    let mut ranges = ranges;
+    let mut accepted = 0;
+
+    for rule in workflow.rules.iter() {
+        let (matching, remaining) = rule.condition.split(&ranges);
+
+        if let Some(matching) = matching {
+            accepted += count(rule.target, matching);
+        }
+
+        match remaining {
+            Some(remaining) => ranges = remaining,
+            None => return accepted,
+        }
+    }
+
+    accepted + count(workflow.fallback, ranges)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    Active,
+    Done,
+}
+
+/// Whether a workflow reachable from `workflow` can send a part back to itself
+fn has_loop(workflows: &[Workflow], workflow: WorkflowId, visits: &mut [Visit]) -> bool {
+    match visits[workflow as usize] {
+        Visit::Active => return true,
+        Visit::Done => return false,
+        Visit::New => visits[workflow as usize] = Visit::Active,
+    }
+
+    let found = workflows[workflow as usize]
+        .targets()
+        .any(|target| match target {
+            Target::Workflow(next) => has_loop(workflows, next, visits),
+            _ => false,
+        });
+    visits[workflow as usize] = Visit::Done;
+
+    found
+}
+
+pub fn parse_input(input: &mut &str) -> Result<Input, AocError> {
+    parse_complete(Day19::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Input> {
+    let named_workflows: Vec<NamedWorkflow> =
+        terminated(separated(1.., parse_workflow, newline), "\n\n").parse_next(input)?;
+    let ratings = separated(1.., parse_rating, newline).parse_next(input)?;
+
+    let mut ids: HashMap<&str, WorkflowId> = HashMap::with_capacity(named_workflows.len());
+    ids.insert("in", START);
+    for (name, _, _) in named_workflows.iter() {
+        let next_id = ids.len() as WorkflowId;
+        ids.entry(name).or_insert(next_id);
+    }
+
+    let target = |name: &str| match name {
+        "A" => Some(Target::Accept),
+        "R" => Some(Target::Reject),
+        name => Some(Target::Workflow(*ids.get(name)?)),
+    };
+
+    let mut workflows = vec![None; ids.len()];
+    for (name, rules, fallback) in named_workflows {
+        let rules = rules
+            .into_iter()
+            .map(|(condition, name)| {
+                let target = target(name)?;
+                Some(Rule { condition, target })
+            })
+            .collect::<Option<Vec<Rule>>>();
+
+        workflows[ids[name] as usize] = rules
+            .zip(target(fallback))
+            .map(|(rules, fallback)| Workflow { rules, fallback });
+    }
+
+    let workflows = workflows
+        .into_iter()
+        .collect::<Option<Vec<Workflow>>>()
+        .filter(|workflows| !has_loop(workflows, START, &mut vec![Visit::New; workflows.len()]))
+        .ok_or_else(|| ErrMode::from_error_kind(input, ErrorKind::Verify))?;
+
+    Ok(Input { workflows, ratings })
+}
+
+/// The last rule of a workflow is the fallback without a condition
+fn parse_workflow<'s>(input: &mut &'s str) -> PResult<NamedWorkflow<'s>> {
+    let name = alpha1.parse_next(input)?;
+    let (rules, fallback) = delimited('{', (repeat(0.., terminated(parse_rule, ',')), alpha1), '}')
+        .parse_next(input)?;
+
+    Ok((name, rules, fallback))
+}
+
+fn parse_rule<'s>(input: &mut &'s str) -> PResult<(Condition, &'s str)> {
+    separated_pair(parse_condition, ':', alpha1).parse_next(input)
+}
+
+fn parse_condition(input: &mut &str) -> PResult<Condition> {
+    let category = parse_category.parse_next(input)?;
+    let operator = one_of(['<', '>']).parse_next(input)?;
+    let value = parse_u16.parse_next(input)?;
+
+    Ok(match operator {
+        '<' => Condition::Less(category, value),
+        _ => Condition::Greater(category, value),
+    })
+}
+
+fn parse_category(input: &mut &str) -> PResult<usize> {
+    alt(('x'.value(0), 'm'.value(1), 'a'.value(2), 's'.value(3))).parse_next(input)
+}
+
+fn parse_rating(input: &mut &str) -> PResult<Rating> {
+    let _ = '{'.parse_next(input)?;
+
+    let mut rating = [0; 4];
+    for (index, separator) in ["x=", ",m=", ",a=", ",s="].into_iter().enumerate() {
+        rating[index] = preceded(separator, parse_u16).parse_next(input)?;
+    }
+
+    let _ = '}'.parse_next(input)?;
+
+    Ok(rating)
+}
+
+pub const EXAMPLE_INPUT: &str = r#"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}
+"#;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "19114"),
+        Example::new(2, EXAMPLE_INPUT, "167409079868000"),
+    ];
+
+    type Input<'s> = Input;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day19::{parse_input, solve1, solve2, Condition, Rule, Target, EXAMPLE_INPUT},
+        error::AocError,
+        utils::interval::Interval,
+    };
+
+    #[test]
+    fn input_parsing() {
+        let input = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(input.workflows.len(), 11);
+        assert_eq!(input.ratings.len(), 5);
+        assert_eq!(input.ratings[0], [787, 2655, 1222, 2876]);
+        assert_eq!(
+            input.workflows[0].rules[0],
+            Rule {
+                condition: Condition::Less(3, 1351),
+                target: Target::Workflow(1),
+            }
+        );
+        assert_eq!(input.workflows[0].fallback, Target::Workflow(8));
+    }
+
+    #[test]
+    fn unknown_workflow() {
+        assert!(parse_input(&mut "in{x<5:foo,A}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(parse_input(&mut "ab{x<5:A,R}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+    }
+
+    #[test]
+    fn missing_fallback() {
+        let error = parse_input(&mut "in{x<5:A,m>3:R}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+
+        assert!(matches!(error, AocError::Parse { location, .. } if location.line == 1));
+    }
+
+    #[test]
+    fn workflow_loops() {
+        let mut looping = "in{x<5:ab,A}\nab{m>3:cd,R}\ncd{in}\n\n{x=1,m=4,a=3,s=4}\n";
+        assert!(matches!(
+            parse_input(&mut looping),
+            Err(AocError::Parse { .. })
+        ));
+
+        let mut unreachable = "in{x<5:A,R}\nab{cd}\ncd{ab}\n\n{x=1,m=4,a=3,s=4}\n";
+        assert!(parse_input(&mut unreachable).is_ok());
+
+        let mut shared = "in{x<5:ab,cd}\nab{cd}\ncd{A}\n\n{x=1,m=4,a=3,s=4}\n";
+        assert_eq!(solve1(parse_input(&mut shared).unwrap()), 12);
+    }
+
+    #[test]
+    fn rating_overflow() {
+        assert!(parse_input(&mut "in{x<70000:A,R}\n\n{x=1,m=2,a=3,s=4}\n").is_err());
+        assert!(parse_input(&mut "in{x<5:A,R}\n\n{x=1,m=2,a=3,s=65536}\n").is_err());
+    }
+
+    #[test]
+    fn split() {
+        let ranges = [Interval::new(1, 4001); 4];
+        let (matching, remaining) = Condition::Greater(1, 2090).split(&ranges);
+
+        assert_eq!(matching.unwrap()[1], Interval::new(2091, 4001));
+        assert_eq!(remaining.unwrap()[1], Interval::new(1, 2091));
+        assert_eq!(Condition::Less(0, 1).split(&ranges), (None, Some(ranges)));
+        assert_eq!(
+            Condition::Greater(0, u16::MAX).split(&ranges),
+            (None, Some(ranges))
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 19114)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(19, 1)
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()),
+            167409079868000
+        )
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(19, 2)
+    }
+}
@@ -0,0 +1,464 @@
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use hashbrown::HashMap;
+use num::integer::lcm;
+use winnow::{
+    ascii::{alpha1, newline},
+    combinator::{alt, preceded, separated, separated_pair},
+    error::{ErrMode, ErrorKind, ParserError},
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::parse_complete,
+};
+
+type ModuleId = u16;
+
+/// Sender of the pulse that every button press starts with
+pub const BUTTON: ModuleId = ModuleId::MAX;
+
+/// Presses after which part 2 gives up finding the cycles
+pub const MAX_PRESSES: u64 = 100_000;
+
+/// Conjunctions keep the last pulse of every input as one bit of a `u64`
+pub const MAX_CONJUNCTION_INPUTS: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// Modules like `rx` that only receive pulses
+    Untyped,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub kind: Kind,
+    /// Receiving modules together with the input slot of a conjunction the pulse arrives at,
+    /// the slot is 0 for all other modules
+    pub outputs: Vec<(ModuleId, u8)>,
+    pub inputs: Vec<ModuleId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'s> {
+    pub lookup: HashMap<&'s str, ModuleId>,
+    pub modules: Vec<Module>,
+    pub broadcaster: ModuleId,
+}
+
+/// Reasons why the presses for `rx` can not be found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RxError {
+    MissingRx,
+    /// `rx` is not fed by exactly one conjunction
+    NoFeeder,
+    /// An input of the feeder was not high twice within [`MAX_PRESSES`]
+    NoCycle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: ModuleId,
+    pub to: ModuleId,
+    pub high: bool,
+}
+
+/// State of all modules between button presses.
+pub struct Network<'i> {
+    modules: &'i [Module],
+    broadcaster: ModuleId,
+    /// Flip-flops use the lowest bit, conjunctions one bit per input slot
+    state: Vec<u64>,
+    queue: VecDeque<(Pulse, u8)>,
+}
+
+impl<'i> Network<'i> {
+    pub fn new(input: &'i Input) -> Network<'i> {
+        Network {
+            modules: &input.modules,
+            broadcaster: input.broadcaster,
+            state: vec![0; input.modules.len()],
+            queue: VecDeque::with_capacity(64),
+        }
+    }
+
+    /// Presses the button once, `on_pulse` sees every pulse in the order it is processed
+    pub fn press(&mut self, mut on_pulse: impl FnMut(Pulse)) {
+        let button = Pulse {
+            from: BUTTON,
+            to: self.broadcaster,
+            high: false,
+        };
+        self.queue.push_back((button, 0));
+
+        while let Some((pulse, slot)) = self.queue.pop_front() {
+            on_pulse(pulse);
+
+            let module = &self.modules[pulse.to as usize];
+            let state = &mut self.state[pulse.to as usize];
+
+            let high = match module.kind {
+                Kind::Broadcaster => pulse.high,
+                Kind::FlipFlop if pulse.high => continue,
+                Kind::FlipFlop => {
+                    *state ^= 1;
+                    *state == 1
+                }
+                Kind::Conjunction => {
+                    if pulse.high {
+                        *state |= 1 << slot;
+                    } else {
+                        *state &= !(1 << slot);
+                    }
+
+                    // The pulse arrived through an input, so there is at least one
+                    *state != u64::MAX >> (u64::BITS as usize - module.inputs.len())
+                }
+                Kind::Untyped => continue,
+            };
+
+            for &(to, slot) in module.outputs.iter() {
+                let pulse = Pulse {
+                    from: pulse.to,
+                    to,
+                    high,
+                };
+                self.queue.push_back((pulse, slot));
+            }
+        }
+    }
+}
+
+pub fn solve1(input: Input) -> u64 {
+    let mut network = Network::new(&input);
+    let mut pulses = [0_u64; 2];
+
+    for _ in 0..1000 {
+        network.press(|pulse| pulses[pulse.high as usize] += 1);
+    }
+
+    pulses[0] * pulses[1]
+}
+
+/// `rx` is fed by a single conjunction, which sends a low pulse once all of its inputs
+/// were high during the same press. Each of those inputs is high in a fixed cycle.
+pub fn solve2(input: Input) -> Result<u64, RxError> {
+    let rx = *input.lookup.get("rx").ok_or(RxError::MissingRx)?;
+    let feeder = match input.modules[rx as usize].inputs[..] {
+        [feeder] if input.modules[feeder as usize].kind == Kind::Conjunction => feeder,
+        _ => return Err(RxError::NoFeeder),
+    };
+    let sources = &input.modules[feeder as usize].inputs;
+
+    let mut first_high: Vec<Option<u64>> = vec![None; sources.len()];
+    let mut cycles: Vec<Option<u64>> = vec![None; sources.len()];
+
+    let mut network = Network::new(&input);
+    let mut presses = 0;
+
+    while cycles.iter().any(Option::is_none) {
+        if presses == MAX_PRESSES {
+            return Err(RxError::NoCycle);
+        }
+        presses += 1;
+
+        network.press(|pulse| {
+            if pulse.to != feeder || !pulse.high {
+                return;
+            }
+
+            let source = sources.iter().position(|&id| id == pulse.from).unwrap();
+            match first_high[source] {
+                None => first_high[source] = Some(presses),
+                Some(first) if cycles[source].is_none() => cycles[source] = Some(presses - first),
+                Some(_) => {}
+            }
+        });
+    }
+
+    Ok(cycles.into_iter().flatten().fold(1, lcm))
+}
+
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Input<'s>, AocError> {
+    parse_complete(Day20::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Input<'s>> {
+    let definitions: Vec<((Kind, &str), Vec<&str>)> =
+        separated(1.., parse_module, newline).parse_next(input)?;
+
+    let mut lookup: HashMap<&str, ModuleId> = HashMap::new();
+    let mut modules: Vec<Module> = Vec::with_capacity(definitions.len() + 1);
+
+    // Every id has to fit into a `ModuleId` and must not be mistaken for the button,
+    // `None` also rejects a name that is defined twice
+    let mut add_module = |lookup: &mut HashMap<_, _>, name, kind| {
+        let id = ModuleId::try_from(modules.len())
+            .ok()
+            .filter(|&id| id != BUTTON)?;
+
+        if lookup.insert(name, id).is_some() {
+            return None;
+        }
+        modules.push(Module {
+            kind,
+            outputs: Vec::new(),
+            inputs: Vec::new(),
+        });
+        Some(id)
+    };
+
+    for &((kind, name), _) in definitions.iter() {
+        add_module(&mut lookup, name, kind)
+            .ok_or_else(|| ErrMode::from_error_kind(input, ErrorKind::Verify))?;
+    }
+
+    let mut wires = Vec::new();
+    for ((_, name), outputs) in definitions {
+        for output in outputs {
+            let receiver = match lookup.get(output) {
+                Some(&receiver) => receiver,
+                None => add_module(&mut lookup, output, Kind::Untyped)
+                    .ok_or_else(|| ErrMode::from_error_kind(input, ErrorKind::Verify))?,
+            };
+            wires.push((lookup[name], receiver));
+        }
+    }
+
+    for (sender, receiver) in wires {
+        let module = &mut modules[receiver as usize];
+        let slot = match module.kind {
+            Kind::Conjunction if module.inputs.len() == MAX_CONJUNCTION_INPUTS => {
+                return Err(ErrMode::from_error_kind(input, ErrorKind::Verify));
+            }
+            Kind::Conjunction => module.inputs.len() as u8,
+            _ => 0,
+        };
+
+        module.inputs.push(sender);
+        modules[sender as usize].outputs.push((receiver, slot));
+    }
+
+    let Some(&broadcaster) = lookup.get("broadcaster") else {
+        return Err(ErrMode::from_error_kind(input, ErrorKind::Verify));
+    };
+
+    Ok(Input {
+        lookup,
+        modules,
+        broadcaster,
+    })
+}
+
+fn parse_module<'s>(input: &mut &'s str) -> PResult<((Kind, &'s str), Vec<&'s str>)> {
+    separated_pair(
+        alt((
+            "broadcaster".map(|name| (Kind::Broadcaster, name)),
+            preceded('%', alpha1).map(|name| (Kind::FlipFlop, name)),
+            preceded('&', alpha1).map(|name| (Kind::Conjunction, name)),
+        )),
+        " -> ",
+        separated(1.., alpha1, ", "),
+    )
+    .parse_next(input)
+}
+
+impl Display for RxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RxError::MissingRx => write!(f, "there is no rx module"),
+            RxError::NoFeeder => write!(f, "rx is not fed by a single conjunction"),
+            RxError::NoCycle => write!(
+                f,
+                "the inputs of the rx feeder do not cycle within {} presses",
+                MAX_PRESSES
+            ),
+        }
+    }
+}
+
+impl Error for RxError {}
+
+pub const EXAMPLE_INPUT_1: &str = r#"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a
+"#;
+
+pub const EXAMPLE_INPUT_2: &str = r#"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output
+"#;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT_1, "32000000"),
+        Example::new(1, EXAMPLE_INPUT_2, "11687500"),
+    ];
+
+    type Input<'s> = Input<'s>;
+    type Output = u64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve2(input).map_err(|error| AocError::solve(Self::DAY, 2, error))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day20::{
+            parse_input, solve1, solve2, Kind, Network, Pulse, RxError, BUTTON, EXAMPLE_INPUT_1,
+            EXAMPLE_INPUT_2,
+        },
+    };
+
+    /// Two counters that reach the conjunction in front of `rx` every 2nd and 4th press
+    const COUNTER_INPUT: &str = r#"broadcaster -> a, b
+%a -> x
+%b -> c
+%c -> y
+&x -> hub
+&y -> hub
+&hub -> rx
+"#;
+
+    #[test]
+    fn input_parsing() {
+        let input = parse_input(&mut EXAMPLE_INPUT_2).unwrap();
+
+        assert_eq!(input.modules.len(), 6);
+        assert_eq!(
+            input.modules[input.lookup["output"] as usize].kind,
+            Kind::Untyped
+        );
+        assert_eq!(
+            input.modules[input.lookup["con"] as usize].inputs,
+            vec![input.lookup["a"], input.lookup["b"]]
+        );
+    }
+
+    #[test]
+    fn invalid_networks() {
+        assert!(parse_input(&mut "broadcaster -> a\n%a -> b\n&a -> b\n").is_err());
+
+        let name = |index: usize| {
+            format!(
+                "{}{}",
+                (b'a' + (index / 26) as u8) as char,
+                (b'a' + (index % 26) as u8) as char
+            )
+        };
+        let network = |inputs: usize| {
+            let names = (0..inputs).map(name).collect::<Vec<_>>();
+            let mut lines = vec![format!("broadcaster -> {}", names.join(", "))];
+            lines.extend(names.iter().map(|name| format!("%{} -> con", name)));
+            lines.push(String::from("&con -> rx"));
+            lines.join("\n")
+        };
+
+        let input = network(64);
+        let input = parse_input(&mut input.as_str()).unwrap();
+        let rx = input.lookup["rx"];
+        let mut low = 0;
+        Network::new(&input).press(|pulse| low += (pulse.to == rx && !pulse.high) as u32);
+        assert_eq!(low, 1);
+
+        assert!(parse_input(&mut network(65).as_str()).is_err());
+    }
+
+    #[test]
+    fn press() {
+        let input = parse_input(&mut EXAMPLE_INPUT_1).unwrap();
+        let mut pulses = Vec::new();
+
+        Network::new(&input).press(|pulse| pulses.push(pulse));
+
+        assert_eq!(pulses.len(), 12);
+        assert_eq!(
+            pulses[0],
+            Pulse {
+                from: BUTTON,
+                to: input.broadcaster,
+                high: false,
+            }
+        );
+        assert_eq!(pulses.iter().filter(|pulse| pulse.high).count(), 4);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_1).unwrap()), 32000000);
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT_2).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(20, 1)
+    }
+
+    #[test]
+    fn part2() {
+        let input = parse_input(&mut COUNTER_INPUT).unwrap();
+        let rx = input.lookup["rx"];
+
+        let mut network = Network::new(&input);
+        let mut presses = 0;
+        let mut done = false;
+        while !done {
+            presses += 1;
+            network.press(|pulse| done |= pulse.to == rx && !pulse.high);
+        }
+
+        assert_eq!(solve2(input), Ok(presses));
+        assert_eq!(presses, 4);
+    }
+
+    #[test]
+    fn unreachable_rx() {
+        let never_high = COUNTER_INPUT.replace("%c -> y", "%c -> a");
+
+        assert_eq!(
+            solve2(parse_input(&mut EXAMPLE_INPUT_1).unwrap()),
+            Err(RxError::MissingRx)
+        );
+        assert_eq!(
+            solve2(parse_input(&mut "broadcaster -> rx\n").unwrap()),
+            Err(RxError::NoFeeder)
+        );
+        assert_eq!(
+            solve2(parse_input(&mut never_high.as_str()).unwrap()),
+            Err(RxError::NoCycle)
+        );
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(20, 2)
+    }
+}
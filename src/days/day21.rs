@@ -0,0 +1,194 @@
+use std::collections::VecDeque;
+
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+use crate::{
+    day09::lagrange_interpolate,
+    error::{AocError, Location},
+    solution::{Example, Solution},
+    utils::{
+        geom::{Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
+
+pub struct Garden {
+    pub grid: Grid<u8>,
+    pub start: Point<i64>,
+}
+
+impl Garden {
+    pub fn width(&self) -> i64 {
+        self.grid.width() as i64
+    }
+
+    pub fn height(&self) -> i64 {
+        self.grid.height() as i64
+    }
+
+    /// Tiled gardens repeat the map in every direction, otherwise everything outside is rock
+    fn is_plot(&self, Point { row, col }: Point<i64>, tiled: bool) -> bool {
+        let (row, col) = if tiled {
+            (row.rem_euclid(self.height()), col.rem_euclid(self.width()))
+        } else if (0..self.height()).contains(&row) && (0..self.width()).contains(&col) {
+            (row, col)
+        } else {
+            return false;
+        };
+
+        self.grid[Point::new(row as usize, col as usize)] != b'#'
+    }
+}
+
+pub fn solve1(input: Garden) -> u64 {
+    reachable(&distance_histogram(&input, 64, false), 64)
+}
+
+/// The start is centered on an empty row and column and the step count leaves it exactly
+/// at the border of a tile, so the number of plots grows quadratically every tile width.
+pub fn solve2(input: Garden) -> u64 {
+    const STEPS: i64 = 26501365;
+
+    let size = input.width();
+    let offset = STEPS % size;
+    let histogram = distance_histogram(&input, (offset + 2 * size) as usize, true);
+
+    let samples = (0..3)
+        .map(|tiles| reachable(&histogram, (offset + tiles * size) as usize))
+        .collect_vec();
+
+    lagrange_interpolate(&samples, STEPS / size) as u64
+}
+
+/// Number of garden plots whose shortest distance from the start is `d`, for all `d <= max_steps`
+pub fn distance_histogram(garden: &Garden, max_steps: usize, tiled: bool) -> Vec<u64> {
+    let mut histogram = vec![0; max_steps + 1];
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(garden.start);
+    queue.push_back((garden.start, 0));
+
+    while let Some((position, steps)) = queue.pop_front() {
+        histogram[steps] += 1;
+
+        if steps == max_steps {
+            continue;
+        }
+
+        for direction in Direction::ALL {
+            let next = position + direction.unit();
+            if garden.is_plot(next, tiled) && visited.insert(next) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    histogram
+}
+
+/// Plots reachable in exactly `steps` steps, these are all plots with a shortest distance
+/// of the same parity, as every plot can be left and entered again.
+pub fn reachable(histogram: &[u64], steps: usize) -> u64 {
+    histogram[..=steps].iter().skip(steps % 2).step_by(2).sum()
+}
+
+pub fn parse_input(input: &str) -> Result<Garden, AocError> {
+    let grid = parse_complete(Day21::DAY, &mut { input }, parse_grid)?;
+
+    let start = grid
+        .positions()
+        .find(|&position| grid[position] == b'S')
+        .map(|position| position.map(|coordinate| coordinate as i64));
+
+    match start {
+        Some(start) => Ok(Garden { grid, start }),
+        None => Err(AocError::Parse {
+            day: Day21::DAY,
+            location: Location::new(input, ""),
+            message: String::from("no starting position"),
+        }),
+    }
+}
+
+pub const EXAMPLE_INPUT: &str = r#"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+"#;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const EXAMPLES: &'static [Example] = &[Example::new(1, EXAMPLE_INPUT, "42")];
+
+    type Input<'s> = Garden;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day21::{distance_histogram, parse_input, reachable, EXAMPLE_INPUT},
+        error::AocError,
+    };
+
+    #[test]
+    fn invalid_garden() {
+        let error = parse_input(".S.\n.\n...\n").err().unwrap();
+        assert!(matches!(error, AocError::Parse { location, .. } if location.line == 2));
+
+        assert!(parse_input("...\n...\n").is_err());
+    }
+
+    #[test]
+    fn part1() {
+        let garden = parse_input(EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(reachable(&distance_histogram(&garden, 6, false), 6), 16)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(21, 1)
+    }
+
+    #[test]
+    fn tiled() {
+        let garden = parse_input(EXAMPLE_INPUT).unwrap();
+        let histogram = distance_histogram(&garden, 100, true);
+
+        assert_eq!(reachable(&histogram, 6), 16);
+        assert_eq!(reachable(&histogram, 10), 50);
+        assert_eq!(reachable(&histogram, 50), 1594);
+        assert_eq!(reachable(&histogram, 100), 6536);
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(21, 2)
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use winnow::{
+    ascii::newline,
+    combinator::{preceded, separated, separated_pair},
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_u16},
+};
+
+/// Corners of a brick in `x, y, z` order, `start` is never larger than `end` and the
+/// ground is below `z = 1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Brick {
+    pub start: [u16; 3],
+    pub end: [u16; 3],
+}
+
+/// Bricks lie within `0..MAX_FOOTPRINT` along `x` and `y`, which bounds the height maps
+pub const MAX_FOOTPRINT: u16 = 1024;
+
+/// Indices refer to the settled bricks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportGraph {
+    pub supports: Vec<Vec<usize>>,
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Brick {
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (start, end) = (self.start, self.end);
+
+        (start[0]..=end[0])
+            .flat_map(move |x| (start[1]..=end[1]).map(move |y| (x as usize, y as usize)))
+    }
+
+    fn footprint_size(bricks: &[Brick]) -> (usize, usize) {
+        bricks.iter().fold((0, 0), |(x, y), brick| {
+            (
+                x.max(brick.end[0] as usize + 1),
+                y.max(brick.end[1] as usize + 1),
+            )
+        })
+    }
+}
+
+pub fn solve1(input: Vec<Brick>) -> usize {
+    let graph = support_graph(&settle(input));
+
+    graph
+        .supports
+        .iter()
+        .filter(|supported| {
+            supported
+                .iter()
+                .all(|&brick| graph.supported_by[brick].len() > 1)
+        })
+        .count()
+}
+
+pub fn solve2(input: Vec<Brick>) -> usize {
+    let graph = support_graph(&settle(input));
+
+    (0..graph.supports.len())
+        .map(|brick| chain_reaction(&graph, brick))
+        .sum()
+}
+
+/// Lets all bricks fall as far as possible, the result is ordered by the bottom of the bricks
+pub fn settle(mut bricks: Vec<Brick>) -> Vec<Brick> {
+    bricks.sort_unstable_by_key(|brick| brick.start[2]);
+
+    let (width, depth) = Brick::footprint_size(&bricks);
+    // Heights are widened so the cell above the highest possible brick fits
+    let mut heights = vec![vec![0_u32; depth]; width];
+
+    for brick in bricks.iter_mut() {
+        let top = brick.cells().map(|(x, y)| heights[x][y]).max().unwrap_or(0);
+        // Overlapping bricks in the snapshot stay where they are
+        let fall = u32::from(brick.start[2]).saturating_sub(top + 1) as u16;
+
+        brick.start[2] -= fall;
+        brick.end[2] -= fall;
+
+        for (x, y) in brick.cells() {
+            heights[x][y] = u32::from(brick.end[2]);
+        }
+    }
+
+    bricks.sort_by_key(|brick| brick.start[2]);
+    bricks
+}
+
+/// Finds the bricks every settled brick rests on and the ones resting on it
+pub fn support_graph(settled: &[Brick]) -> SupportGraph {
+    let (width, depth) = Brick::footprint_size(settled);
+    let mut tops: Vec<Vec<Option<usize>>> = vec![vec![None; depth]; width];
+
+    let mut supports = vec![Vec::new(); settled.len()];
+    let mut supported_by = vec![Vec::new(); settled.len()];
+
+    for (index, brick) in settled.iter().enumerate() {
+        let below = brick
+            .cells()
+            .filter_map(|(x, y)| tops[x][y])
+            .filter(|&other: &usize| {
+                u32::from(settled[other].end[2]) + 1 == u32::from(brick.start[2])
+            })
+            .unique()
+            .collect_vec();
+
+        for &other in below.iter() {
+            supports[other].push(index);
+        }
+        supported_by[index] = below;
+
+        for (x, y) in brick.cells() {
+            tops[x][y] = Some(index);
+        }
+    }
+
+    SupportGraph {
+        supports,
+        supported_by,
+    }
+}
+
+/// Number of other bricks that fall if `brick` is disintegrated
+pub fn chain_reaction(graph: &SupportGraph, brick: usize) -> usize {
+    let mut remaining = graph.supported_by.iter().map(Vec::len).collect_vec();
+    let mut queue = VecDeque::from([brick]);
+    let mut fallen = 0;
+
+    while let Some(current) = queue.pop_front() {
+        for &above in graph.supports[current].iter() {
+            remaining[above] -= 1;
+
+            if remaining[above] == 0 {
+                fallen += 1;
+                queue.push_back(above);
+            }
+        }
+    }
+
+    fallen
+}
+
+pub fn parse_input(input: &mut &str) -> Result<Vec<Brick>, AocError> {
+    parse_complete(Day22::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Vec<Brick>> {
+    separated(1.., parse_brick, newline).parse_next(input)
+}
+
+fn parse_brick(input: &mut &str) -> PResult<Brick> {
+    let (a, b) = separated_pair(parse_corner, '~', parse_corner).parse_next(input)?;
+
+    Ok(Brick {
+        start: [a[0].min(b[0]), a[1].min(b[1]), a[2].min(b[2])],
+        end: [a[0].max(b[0]), a[1].max(b[1]), a[2].max(b[2])],
+    })
+}
+
+fn parse_corner(input: &mut &str) -> PResult<[u16; 3]> {
+    let x = parse_u16.verify(|&x| x < MAX_FOOTPRINT).parse_next(input)?;
+    let y = preceded(',', parse_u16.verify(|&y| y < MAX_FOOTPRINT)).parse_next(input)?;
+    let z = preceded(',', parse_u16.verify(|&z| z >= 1)).parse_next(input)?;
+
+    Ok([x, y, z])
+}
+
+pub const EXAMPLE_INPUT: &str = r#"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9
+"#;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "5"),
+        Example::new(2, EXAMPLE_INPUT, "7"),
+    ];
+
+    type Input<'s> = Vec<Brick>;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve2(input))
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day22::{
+            chain_reaction, parse_input, settle, solve1, solve2, support_graph, Brick,
+            EXAMPLE_INPUT,
+        },
+    };
+
+    #[test]
+    fn settling() {
+        let settled = settle(parse_input(&mut EXAMPLE_INPUT).unwrap());
+
+        let bottoms = settled
+            .iter()
+            .map(|brick| brick.start[2])
+            .collect::<Vec<_>>();
+        assert_eq!(bottoms, vec![1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(
+            settled[6],
+            Brick {
+                start: [1, 1, 5],
+                end: [1, 1, 6],
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_bricks() {
+        assert!(parse_input(&mut "1,0,0~1,2,1\n").is_err());
+        assert!(parse_input(&mut "1,0,1~70000,2,1\n").is_err());
+        assert!(parse_input(&mut "1,0,1~1,65535,1\n").is_err());
+
+        let overlapping = parse_input(&mut "0,0,1~0,0,2\n0,0,2~0,0,3\n").unwrap();
+        assert_eq!(settle(overlapping)[1].start[2], 2);
+
+        let tallest = parse_input(&mut "0,0,1~0,0,65535\n0,0,2~0,0,3").unwrap();
+        assert_eq!(solve1(tallest.clone()), 2);
+        assert_eq!(solve2(tallest), 0);
+    }
+
+    #[test]
+    fn graph() {
+        let settled = settle(parse_input(&mut EXAMPLE_INPUT).unwrap());
+        let graph = support_graph(&settled);
+
+        assert_eq!(graph.supported_by[0], Vec::<usize>::new());
+        assert_eq!(graph.supports[0].len(), 2);
+        assert_eq!(graph.supported_by[5].len(), 2);
+        assert_eq!(graph.supports[6], Vec::<usize>::new());
+
+        assert_eq!(chain_reaction(&graph, 0), 6);
+        assert_eq!(chain_reaction(&graph, 5), 1);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), 5)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(22, 1)
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve2(parse_input(&mut EXAMPLE_INPUT).unwrap()), 7)
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(22, 2)
+    }
+}
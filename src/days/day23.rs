@@ -0,0 +1,373 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    error::{AocError, Location},
+    solution::{Example, Solution},
+    utils::{
+        geom::{Direction, Point},
+        grid::{parse_grid, Grid},
+        parse_complete,
+    },
+};
+
+/// Tiles of the map with the openings in the first and the last row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailMap {
+    pub grid: Grid<u8>,
+    pub start: Point<usize>,
+    pub end: Point<usize>,
+}
+
+/// Paths of this many junctions are searched in parallel
+const PARALLEL_DEPTH: usize = 6;
+
+/// Junctions are tracked in a `u128` bitset while searching
+pub const MAX_JUNCTIONS: usize = u128::BITS as usize;
+
+/// Current junction, the visited junctions and the length so far
+type Path = (usize, u128, usize);
+
+/// Junctions of the trail map connected by the lengths of the trails between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailGraph {
+    pub edges: Vec<Vec<(usize, usize)>>,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HikeError {
+    /// The map has more junctions than [`MAX_JUNCTIONS`]
+    TooManyJunctions(usize),
+    NoPath,
+}
+
+pub fn solve1(input: TrailMap) -> Result<usize, HikeError> {
+    longest_path(&junction_graph(&input, true)?).ok_or(HikeError::NoPath)
+}
+
+pub fn solve2(input: TrailMap) -> Result<usize, HikeError> {
+    longest_path(&junction_graph(&input, false)?).ok_or(HikeError::NoPath)
+}
+
+/// Compresses the map into its junctions, the start and the end are junctions as well
+pub fn junction_graph(map: &TrailMap, slopes: bool) -> Result<TrailGraph, HikeError> {
+    let (grid, start, end) = (&map.grid, map.start, map.end);
+
+    let junctions: HashMap<Point<usize>, usize> = grid
+        .positions()
+        .filter(|&point| {
+            point == start || point == end || {
+                grid[point] != b'#' && neighbours(grid, point, false).count() > 2
+            }
+        })
+        .enumerate()
+        .map(|(id, point)| (point, id))
+        .collect();
+
+    if junctions.len() > MAX_JUNCTIONS {
+        return Err(HikeError::TooManyJunctions(junctions.len()));
+    }
+
+    let mut edges = vec![Vec::new(); junctions.len()];
+
+    for (&junction, &id) in junctions.iter() {
+        for mut current in neighbours(grid, junction, slopes) {
+            let mut previous = junction;
+            let mut length = 1;
+
+            while !junctions.contains_key(&current) {
+                let Some(next) = neighbours(grid, current, slopes).find(|&next| next != previous)
+                else {
+                    break;
+                };
+
+                (previous, current) = (current, next);
+                length += 1;
+            }
+
+            if let Some(&next) = junctions.get(&current) {
+                edges[id].push((next, length));
+            }
+        }
+    }
+
+    Ok(TrailGraph {
+        edges,
+        start: junctions[&start],
+        end: junctions[&end],
+    })
+}
+
+/// Open tiles next to `point`, with `slopes` a slope can only be left downhill
+fn neighbours(
+    grid: &Grid<u8>,
+    position: Point<usize>,
+    slopes: bool,
+) -> impl Iterator<Item = Point<usize>> + '_ {
+    let directions: &[Direction] = match grid[position] {
+        b'^' if slopes => &[Direction::Up],
+        b'>' if slopes => &[Direction::Right],
+        b'v' if slopes => &[Direction::Down],
+        b'<' if slopes => &[Direction::Left],
+        _ => &Direction::ALL,
+    };
+
+    directions.iter().filter_map(move |&direction| {
+        let next = grid.step(position, direction)?;
+
+        (grid[next] != b'#').then_some(next)
+    })
+}
+
+/// Length of the longest path from start to end that visits no junction twice
+pub fn longest_path(graph: &TrailGraph) -> Option<usize> {
+    // Once the last junction in front of the end is reached, the end has to be next
+    let predecessors = (0..graph.edges.len())
+        .filter(|&node| graph.edges[node].iter().any(|&(next, _)| next == graph.end))
+        .collect_vec();
+    let target = match predecessors[..] {
+        [node] => node,
+        _ => graph.end,
+    };
+    let target_length = graph.edges[target]
+        .iter()
+        .find(|&&(next, _)| next == graph.end)
+        .map_or(0, |&(_, length)| length);
+
+    let mut paths: Vec<Path> = vec![(graph.start, 1 << graph.start, 0)];
+    for _ in 0..PARALLEL_DEPTH {
+        paths = paths
+            .into_iter()
+            .flat_map(|(node, visited, length)| -> Vec<Path> {
+                if node == target {
+                    return vec![(node, visited, length)];
+                }
+
+                graph.edges[node]
+                    .iter()
+                    .filter(|&&(next, _)| visited & 1 << next == 0)
+                    .map(|&(next, edge)| (next, visited | 1 << next, length + edge))
+                    .collect()
+            })
+            .collect();
+    }
+
+    paths
+        .into_par_iter()
+        .filter_map(|(node, visited, length)| {
+            search(graph, target, node, visited).map(|rest| length + rest)
+        })
+        .max()
+        .map(|length| length + target_length)
+}
+
+/// Longest path from `node` to `target` that avoids the `visited` junctions
+fn search(graph: &TrailGraph, target: usize, node: usize, visited: u128) -> Option<usize> {
+    if node == target {
+        return Some(0);
+    }
+
+    graph.edges[node]
+        .iter()
+        .filter(|&&(next, _)| visited & 1 << next == 0)
+        .filter_map(|&(next, length)| {
+            search(graph, target, next, visited | 1 << next).map(|rest| rest + length)
+        })
+        .max()
+}
+
+pub fn parse_input(input: &str) -> Result<TrailMap, AocError> {
+    let grid = parse_complete(Day23::DAY, &mut { input }, parse_grid)?;
+    let opening = |row: usize| grid.row(row).iter().position(|&tile| tile == b'.');
+
+    let error = |offset: usize, message: &str| AocError::Parse {
+        day: Day23::DAY,
+        location: Location::new(input, &input[offset..]),
+        message: String::from(message),
+    };
+
+    if grid.height() < 2 {
+        return Err(error(0, "the map needs at least two rows"));
+    }
+    let last = grid.height() - 1;
+
+    let Some(start) = opening(0) else {
+        return Err(error(0, "no opening in the first row"));
+    };
+    let Some(end) = opening(last) else {
+        let last_line = input.trim_end().rfind('\n').map_or(0, |index| index + 1);
+        return Err(error(last_line, "no opening in the last row"));
+    };
+
+    Ok(TrailMap {
+        start: Point::new(0, start),
+        end: Point::new(last, end),
+        grid,
+    })
+}
+
+pub const EXAMPLE_INPUT: &str = r#"#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#
+"#;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const EXAMPLES: &'static [Example] = &[
+        Example::new(1, EXAMPLE_INPUT, "94"),
+        Example::new(2, EXAMPLE_INPUT, "154"),
+    ];
+
+    type Input<'s> = TrailMap;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve1(input).map_err(|error| AocError::solve(Self::DAY, 1, error))
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve2(input).map_err(|error| AocError::solve(Self::DAY, 2, error))
+    }
+}
+
+impl Display for HikeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HikeError::TooManyJunctions(count) => write!(
+                f,
+                "the map has {} junctions, at most {} are supported",
+                count, MAX_JUNCTIONS
+            ),
+            HikeError::NoPath => write!(f, "there is no path from start to end"),
+        }
+    }
+}
+
+impl Error for HikeError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day23::{
+            junction_graph, parse_input, solve1, solve2, Day23, HikeError, TrailGraph,
+            EXAMPLE_INPUT,
+        },
+        error::AocError,
+        solution::Solution,
+    };
+
+    #[test]
+    fn graph() {
+        let input = parse_input(EXAMPLE_INPUT).unwrap();
+        let graph = junction_graph(&input, false).unwrap();
+
+        assert_eq!(graph.edges.len(), 9);
+        assert_eq!(graph.start, 0);
+        assert_eq!(graph.end, 8);
+        assert_eq!(graph.edges[graph.start].len(), 1);
+        assert_eq!(graph.edges[graph.start][0].1, 15);
+
+        let directed = junction_graph(&input, true).unwrap();
+        let edges = |graph: &TrailGraph| graph.edges.iter().map(Vec::len).sum::<usize>();
+        assert_eq!(edges(&directed) * 2, edges(&graph));
+    }
+
+    #[test]
+    fn invalid_map() {
+        assert!(parse_input("").is_err());
+        assert!(parse_input("#.#\n").is_err());
+        assert!(parse_input("###\n#.#\n#.#\n").is_err());
+
+        let error = parse_input("#.#\n#.#\n###\n").unwrap_err();
+        assert!(matches!(error, AocError::Parse { location, .. } if location.line == 3));
+    }
+
+    #[test]
+    fn many_junctions() {
+        // Almost every cell of a 12x12 lattice is a junction, more than fit the bitset
+        let mut map = vec![String::from("#.") + &"#".repeat(23)];
+        map.extend((0..23).map(|row| {
+            if row % 2 == 0 {
+                String::from("#") + &".".repeat(23) + "#"
+            } else {
+                (0..25)
+                    .map(|col| if col % 2 == 1 { '.' } else { '#' })
+                    .collect()
+            }
+        }));
+        map.push("#".repeat(23) + ".#");
+
+        let input = parse_input(&map.join("\n")).unwrap();
+        assert_eq!(
+            junction_graph(&input, false),
+            Err(HikeError::TooManyJunctions(144))
+        );
+        assert_eq!(solve2(input), Err(HikeError::TooManyJunctions(144)));
+    }
+
+    #[test]
+    fn no_path() {
+        let input = parse_input("#.#\n###\n#.#\n").unwrap();
+        assert_eq!(solve1(input.clone()), Err(HikeError::NoPath));
+
+        assert_eq!(
+            Day23::part2(input).unwrap_err().to_string(),
+            "Day 23 Part 2: there is no path from start to end"
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(EXAMPLE_INPUT).unwrap()), Ok(94))
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(23, 1)
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(solve2(parse_input(EXAMPLE_INPUT).unwrap()), Ok(154))
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(23, 2)
+    }
+}
@@ -0,0 +1,350 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use itertools::Itertools;
+use num::{BigInt, BigRational, Signed, ToPrimitive, Zero};
+use winnow::{
+    ascii::{newline, space0, space1},
+    combinator::{delimited, preceded, separated, separated_pair},
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::{parse_complete, parse_i64},
+};
+
+type Vector = [i128; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pub position: Vector,
+    pub velocity: Vector,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrowError {
+    NoThrow,
+    /// The coordinates of the throw position do not sum up to an `i64`
+    TooLarge,
+}
+
+pub fn solve1(input: Vec<Hailstone>) -> usize {
+    count_intersections(&input, 200000000000000, 400000000000000)
+}
+
+pub fn solve2(input: Vec<Hailstone>) -> Result<i64, ThrowError> {
+    let position = throw_position(&input).ok_or(ThrowError::NoThrow)?;
+
+    position
+        .iter()
+        .try_fold(0_i128, |sum, &coordinate| sum.checked_add(coordinate))
+        .and_then(|sum| i64::try_from(sum).ok())
+        .ok_or(ThrowError::TooLarge)
+}
+
+/// Pairs of hailstones whose future paths cross within `min..=max` in x and y, ignoring z
+pub fn count_intersections(hailstones: &[Hailstone], min: i128, max: i128) -> usize {
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| {
+            intersection_2d(a, b).is_some_and(|(x, y, det)| {
+                // The point is (x / det, y / det) with a positive det
+                (min * det..=max * det).contains(&x) && (min * det..=max * det).contains(&y)
+            })
+        })
+        .count()
+}
+
+/// Crossing point of two future paths in the x/y plane as `(x, y, det)`, both coordinates
+/// have to be divided by `det`.
+fn intersection_2d(a: &Hailstone, b: &Hailstone) -> Option<(i128, i128, i128)> {
+    let ([ax, ay, _], [avx, avy, _]) = (a.position, a.velocity);
+    let ([bx, by, _], [bvx, bvy, _]) = (b.position, b.velocity);
+
+    let det = avx * bvy - avy * bvx;
+    if det == 0 {
+        return None;
+    }
+
+    // a.position + t * a.velocity == b.position + s * b.velocity, both scaled by det
+    let t = (bx - ax) * bvy - (by - ay) * bvx;
+    let s = (bx - ax) * avy - (by - ay) * avx;
+
+    let sign = det.signum();
+    if t * sign < 0 || s * sign < 0 {
+        return None;
+    }
+
+    Some((
+        (ax * det + t * avx) * sign,
+        (ay * det + t * avy) * sign,
+        det.abs(),
+    ))
+}
+
+/// Finds the position from which a rock thrown in a straight line hits every hailstone.
+///
+/// `(P - p_i) x (V - v_i) = 0` holds for every hailstone `i`, subtracting it for two
+/// hailstones cancels `P x V` and leaves three linear equations. Two pairs are enough,
+/// as long as the solution hits every hailstone.
+pub fn throw_position(hailstones: &[Hailstone]) -> Option<Vector> {
+    let first = hailstones.first()?;
+
+    hailstones[1..]
+        .iter()
+        .tuple_combinations()
+        .find_map(|(second, third)| {
+            let mut system = Vec::with_capacity(6);
+            system.extend(pair_equations(first, second));
+            system.extend(pair_equations(first, third));
+
+            let solution = solve_linear(system)?;
+            if !hailstones
+                .iter()
+                .all(|hailstone| hits(&solution, hailstone))
+            {
+                return None;
+            }
+
+            let position = [0, 1, 2].map(|axis| solution[axis].to_integer().to_i128());
+
+            match position {
+                [Some(x), Some(y), Some(z)]
+                    if solution[..3].iter().all(|value| value.is_integer()) =>
+                {
+                    Some([x, y, z])
+                }
+                _ => None,
+            }
+        })
+}
+
+/// Whether the rock `[P_x, P_y, P_z, V_x, V_y, V_z]` meets `hailstone` at some time `t >= 0`
+fn hits(rock: &[BigRational], hailstone: &Hailstone) -> bool {
+    let rational = |value: i128| BigRational::from_integer(BigInt::from(value));
+    let (position, velocity) = rock.split_at(3);
+
+    // P + t * V == p + t * v, so the offset P - p is t times the closing velocity v - V
+    let offset = [0, 1, 2].map(|axis| &position[axis] - rational(hailstone.position[axis]));
+    let closing = [0, 1, 2].map(|axis| rational(hailstone.velocity[axis]) - &velocity[axis]);
+
+    match (0..3).find(|&axis| !closing[axis].is_zero()) {
+        Some(axis) => {
+            let time = &offset[axis] / &closing[axis];
+
+            !time.is_negative() && (0..3).all(|axis| offset[axis] == &time * &closing[axis])
+        }
+        None => offset.iter().all(Zero::is_zero),
+    }
+}
+
+/// Rows `[P_x, P_y, P_z, V_x, V_y, V_z | rhs]` of `P x (v_j - v_i) + (p_j - p_i) x V = p_j x v_j - p_i x v_i`
+fn pair_equations(i: &Hailstone, j: &Hailstone) -> [[i128; 7]; 3] {
+    let w = [0, 1, 2].map(|axis| j.velocity[axis] - i.velocity[axis]);
+    let u = [0, 1, 2].map(|axis| j.position[axis] - i.position[axis]);
+
+    let (a, b) = (cross(j.position, j.velocity), cross(i.position, i.velocity));
+    let rhs = [0, 1, 2].map(|axis| a[axis] - b[axis]);
+
+    [
+        [0, w[2], -w[1], 0, -u[2], u[1], rhs[0]],
+        [-w[2], 0, w[0], u[2], 0, -u[0], rhs[1]],
+        [w[1], -w[0], 0, -u[1], u[0], 0, rhs[2]],
+    ]
+}
+
+fn cross(a: Vector, b: Vector) -> Vector {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Gaussian elimination on an augmented matrix, `None` if the system is singular
+fn solve_linear(system: Vec<[i128; 7]>) -> Option<Vec<BigRational>> {
+    let mut rows = system
+        .into_iter()
+        .map(|row| row.map(|value| BigRational::from_integer(BigInt::from(value))))
+        .collect_vec();
+    let unknowns = rows[0].len() - 1;
+
+    for column in 0..unknowns {
+        let pivot = (column..rows.len()).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let pivot_row = rows[column].clone();
+
+        for (index, row) in rows.iter_mut().enumerate() {
+            if index == column || row[column].is_zero() {
+                continue;
+            }
+
+            let factor = &row[column] / &pivot_row[column];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()).skip(column) {
+                *value -= &factor * pivot_value;
+            }
+        }
+    }
+
+    Some(
+        (0..unknowns)
+            .map(|row| &rows[row][unknowns] / &rows[row][row])
+            .collect(),
+    )
+}
+
+pub fn parse_input(input: &mut &str) -> Result<Vec<Hailstone>, AocError> {
+    parse_complete(Day24::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle(input: &mut &str) -> PResult<Vec<Hailstone>> {
+    separated(1.., parse_hailstone, newline).parse_next(input)
+}
+
+fn parse_hailstone(input: &mut &str) -> PResult<Hailstone> {
+    let (position, velocity) =
+        separated_pair(parse_vector, delimited(space0, '@', space0), parse_vector)
+            .parse_next(input)?;
+
+    Ok(Hailstone { position, velocity })
+}
+
+fn parse_vector(input: &mut &str) -> PResult<Vector> {
+    let x = parse_i64.parse_next(input)?;
+    let y = preceded((',', space1), parse_i64).parse_next(input)?;
+    let z = preceded((',', space1), parse_i64).parse_next(input)?;
+
+    Ok([x as i128, y as i128, z as i128])
+}
+
+pub const EXAMPLE_INPUT: &str = r#"19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+"#;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    /// The part 1 example uses a smaller test area, see the tests
+    const EXAMPLES: &'static [Example] = &[Example::new(2, EXAMPLE_INPUT, "47")];
+
+    type Input<'s> = Vec<Hailstone>;
+    type Output = i64;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Ok(solve1(input) as i64)
+    }
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve2(input).map_err(|error| AocError::solve(Self::DAY, 2, error))
+    }
+}
+
+impl Display for ThrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThrowError::NoThrow => write!(f, "no throw hits all hailstones"),
+            ThrowError::TooLarge => write!(f, "the throw position is too large"),
+        }
+    }
+}
+
+impl Error for ThrowError {}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day24::{
+            count_intersections, parse_input, solve2, throw_position, Hailstone, ThrowError,
+            EXAMPLE_INPUT,
+        },
+    };
+
+    #[test]
+    fn input_parsing() {
+        let hailstones = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(hailstones.len(), 5);
+        assert_eq!(
+            hailstones[4],
+            Hailstone {
+                position: [20, 19, 15],
+                velocity: [1, -5, -3],
+            }
+        );
+    }
+
+    #[test]
+    fn part1() {
+        let hailstones = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(count_intersections(&hailstones, 7, 27), 2)
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(24, 1)
+    }
+
+    #[test]
+    fn part2() {
+        let hailstones = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(throw_position(&hailstones), Some([24, 13, 10]));
+        assert_eq!(solve2(hailstones), Ok(47))
+    }
+
+    #[test]
+    fn no_throw() {
+        let two = parse_input(&mut "0, 0, 0 @ 1, 0, 0\n0, 5, 0 @ 0, 1, 0\n").unwrap();
+        assert_eq!(solve2(two), Err(ThrowError::NoThrow));
+
+        // The last hailstone is not on the path of the throw through the first four
+        let mut hailstones = parse_input(&mut EXAMPLE_INPUT).unwrap();
+        hailstones[4].position[0] += 1;
+        assert_eq!(solve2(hailstones), Err(ThrowError::NoThrow));
+    }
+
+    #[test]
+    fn large_throw() {
+        // Hailstones at `P + t * (V - v)`, the coordinates of `P` sum up to more than `i64::MAX`
+        let rock = [i64::MAX / 2; 3];
+        let throw = [1, 2, 3];
+        let hailstones = [
+            ([0, 0, 0], 1),
+            ([1, -1, 2], 2),
+            ([-2, 1, 0], 3),
+            ([3, 3, -1], 4),
+        ]
+        .map(|(velocity, time): ([i64; 3], i64)| {
+            let position = [0, 1, 2].map(|axis| rock[axis] + time * (throw[axis] - velocity[axis]));
+            Hailstone {
+                position: position.map(i128::from),
+                velocity: velocity.map(i128::from),
+            }
+        });
+
+        assert_eq!(throw_position(&hailstones), Some(rock.map(i128::from)));
+        assert_eq!(solve2(hailstones.to_vec()), Err(ThrowError::TooLarge));
+    }
+
+    #[test]
+    fn solve_part2() {
+        assert_answer(24, 2)
+    }
+}
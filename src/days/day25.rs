@@ -0,0 +1,248 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt,
+};
+
+use pathfinding::directed::bfs::bfs_reach;
+use winnow::{
+    ascii::{alpha1, newline},
+    combinator::{separated, separated_pair},
+    PResult, Parser,
+};
+
+use crate::{
+    error::AocError,
+    solution::{Example, Solution},
+    utils::parse_complete,
+};
+
+/// Undirected edge with the smaller component id first
+pub type Edge = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wiring<'s> {
+    pub lookup: HashMap<&'s str, usize>,
+    pub neighbours: Vec<Vec<usize>>,
+}
+
+impl Wiring<'_> {
+    fn connected<'a>(&'a self, node: usize, cut: &'a [Edge]) -> impl Iterator<Item = usize> + 'a {
+        self.neighbours[node]
+            .iter()
+            .copied()
+            .filter(move |&next| !cut.contains(&edge(node, next)))
+    }
+}
+
+/// No three wires split the components into two groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoCut;
+
+impl fmt::Display for NoCut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no cut of three wires splits the components")
+    }
+}
+
+impl Error for NoCut {}
+
+fn edge(a: usize, b: usize) -> Edge {
+    (a.min(b), a.max(b))
+}
+
+pub fn solve1(input: Wiring) -> Result<usize, NoCut> {
+    let cut = minimum_cut(&input, 3).ok_or(NoCut)?;
+    let size = component_size(&input, 0, &cut);
+
+    Ok(size * (input.neighbours.len() - size))
+}
+
+/// Finds `size` wires that split the components into two groups.
+///
+/// Wires between the groups carry the most shortest paths, so the busiest wire is
+/// removed one at a time before counting again.
+pub fn minimum_cut(wiring: &Wiring, size: usize) -> Option<Vec<Edge>> {
+    let mut cut = Vec::with_capacity(size);
+
+    for _ in 0..size {
+        let busiest = edge_betweenness(wiring, &cut)
+            .into_iter()
+            .max_by_key(|&(edge, count)| (count, std::cmp::Reverse(edge)))?;
+        cut.push(busiest.0);
+    }
+
+    (component_size(wiring, 0, &cut) < wiring.neighbours.len()).then_some(cut)
+}
+
+/// Number of shortest paths from every component that use each wire, ignoring the `cut` wires
+pub fn edge_betweenness(wiring: &Wiring, cut: &[Edge]) -> HashMap<Edge, usize> {
+    let mut counts = HashMap::new();
+    let mut parents = vec![None; wiring.neighbours.len()];
+    let mut queue = VecDeque::new();
+    let mut order = Vec::with_capacity(wiring.neighbours.len());
+
+    for start in 0..wiring.neighbours.len() {
+        parents.fill(None);
+        parents[start] = Some(start);
+        queue.push_back(start);
+        order.clear();
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+
+            for next in wiring.connected(node, cut) {
+                if parents[next].is_none() {
+                    parents[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        // Paths through a wire are the nodes below it in the tree, children come later
+        let mut below = vec![1_usize; wiring.neighbours.len()];
+        for &node in order.iter().skip(1).rev() {
+            let parent = parents[node].unwrap();
+            below[parent] += below[node];
+            *counts.entry(edge(node, parent)).or_insert(0) += below[node];
+        }
+    }
+
+    counts
+}
+
+/// Components reachable from `start` without using the `cut` wires, including itself
+pub fn component_size(wiring: &Wiring, start: usize, cut: &[Edge]) -> usize {
+    bfs_reach(start, |&node| wiring.connected(node, cut)).count()
+}
+
+pub fn parse_input<'s>(input: &mut &'s str) -> Result<Wiring<'s>, AocError> {
+    parse_complete(Day25::DAY, input, parse_puzzle)
+}
+
+fn parse_puzzle<'s>(input: &mut &'s str) -> PResult<Wiring<'s>> {
+    let lines: Vec<(&str, Vec<&str>)> = separated(1.., parse_line, newline).parse_next(input)?;
+
+    let mut lookup = HashMap::new();
+    let mut neighbours: Vec<Vec<usize>> = Vec::new();
+    let mut id = |name| {
+        *lookup.entry(name).or_insert_with(|| {
+            neighbours.push(Vec::new());
+            neighbours.len() - 1
+        })
+    };
+
+    let mut edges = Vec::new();
+    for (name, others) in lines {
+        let from = id(name);
+        edges.extend(others.into_iter().map(|other| (from, id(other))));
+    }
+
+    for (from, to) in edges {
+        neighbours[from].push(to);
+        neighbours[to].push(from);
+    }
+
+    Ok(Wiring { lookup, neighbours })
+}
+
+fn parse_line<'s>(input: &mut &'s str) -> PResult<(&'s str, Vec<&'s str>)> {
+    separated_pair(alpha1, ": ", separated(1.., alpha1, ' ')).parse_next(input)
+}
+
+pub const EXAMPLE_INPUT: &str = r#"jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+"#;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const EXAMPLES: &'static [Example] = &[Example::new(1, EXAMPLE_INPUT, "54")];
+    const PARTS: u8 = 1;
+
+    type Input<'s> = Wiring<'s>;
+    type Output = usize;
+
+    fn parse(mut input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_input(&mut input)
+    }
+
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        solve1(input).map_err(|error| AocError::solve(Self::DAY, 1, error))
+    }
+
+    fn part2(_input: Self::Input<'_>) -> Result<Self::Output, AocError> {
+        Err(AocError::UnknownPart {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
+
+#[allow(const_item_mutation)]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        answers::assert_answer,
+        day25::{component_size, edge, minimum_cut, parse_input, solve1, Day25, EXAMPLE_INPUT},
+        solution::Day,
+    };
+
+    #[test]
+    fn input_parsing() {
+        let wiring = parse_input(&mut EXAMPLE_INPUT).unwrap();
+
+        assert_eq!(wiring.neighbours.len(), 15);
+        assert_eq!(wiring.neighbours.iter().map(Vec::len).sum::<usize>(), 66);
+    }
+
+    #[test]
+    fn cut() {
+        let wiring = parse_input(&mut EXAMPLE_INPUT).unwrap();
+        let mut cut = minimum_cut(&wiring, 3).unwrap();
+        cut.sort();
+
+        let mut expected = [("hfx", "pzl"), ("bvb", "cmg"), ("nvd", "jqt")]
+            .map(|(a, b)| edge(wiring.lookup[a], wiring.lookup[b]));
+        expected.sort();
+
+        assert_eq!(cut, expected);
+        assert_eq!(component_size(&wiring, wiring.lookup["jqt"], &cut), 6);
+        assert_eq!(minimum_cut(&wiring, 2), None);
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(solve1(parse_input(&mut EXAMPLE_INPUT).unwrap()), Ok(54))
+    }
+
+    #[test]
+    fn no_cut() {
+        assert_eq!(
+            Day25.solve("a: b", 1).unwrap_err().to_string(),
+            "Day 25 Part 1: no cut of three wires splits the components"
+        );
+        assert_eq!(
+            Day25.solve(EXAMPLE_INPUT, 2).unwrap_err().to_string(),
+            "Day 25 has no part 2"
+        );
+        assert!(Day25.solve_timed(EXAMPLE_INPUT, 0).is_err());
+    }
+
+    #[test]
+    fn solve_part1() {
+        assert_answer(25, 1)
+    }
+}
@@ -0,0 +1,145 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+/// Errors that can occur while reading or parsing a puzzle input.
+#[derive(Debug)]
+pub enum AocError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Parse {
+        day: u8,
+        location: Location,
+        message: String,
+    },
+    LeftoverInput {
+        day: u8,
+        location: Location,
+    },
+    /// The input parsed fine, but a part has no answer for it
+    Solve {
+        day: u8,
+        part: u8,
+        error: Box<dyn Error + Send + Sync>,
+    },
+    /// The day has no puzzle part with this number
+    UnknownPart {
+        day: u8,
+        part: u8,
+    },
+}
+
+/// Position of the offending part of the input together with the whole line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+}
+
+impl AocError {
+    pub fn solve(day: u8, part: u8, error: impl Error + Send + Sync + 'static) -> AocError {
+        AocError::Solve {
+            day,
+            part,
+            error: Box::new(error),
+        }
+    }
+}
+
+impl Location {
+    /// Locates `remaining`, which must be a suffix of `input`
+    pub fn new(input: &str, remaining: &str) -> Location {
+        let offset = input.len() - remaining.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        Location {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end].to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, error } => {
+                write!(f, "unable to read {}: {}", path.display(), error)
+            }
+            AocError::Parse {
+                day,
+                location,
+                message,
+            } => {
+                write!(
+                    f,
+                    "Day {:0>2}: {} at line {}, column {}",
+                    day, message, location.line, location.column
+                )?;
+                location.fmt(f)
+            }
+            AocError::LeftoverInput { day, location } => {
+                write!(
+                    f,
+                    "Day {:0>2}: could not fully parse input, unexpected input at line {}, column {}",
+                    day, location.line, location.column
+                )?;
+                location.fmt(f)
+            }
+            AocError::Solve { day, part, error } => {
+                write!(f, "Day {:0>2} Part {}: {}", day, part, error)
+            }
+            AocError::UnknownPart { day, part } => {
+                write!(f, "Day {:0>2} has no part {}", day, part)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\n    {}\n    {:>width$}",
+            self.snippet,
+            "^",
+            width = self.column
+        )
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { error, .. } => Some(error),
+            AocError::Solve { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Location;
+
+    #[test]
+    fn location() {
+        let input = "first line\nsecond line\n";
+
+        assert_eq!(
+            Location::new(input, &input[18..]),
+            Location {
+                line: 2,
+                column: 8,
+                snippet: String::from("second line"),
+            }
+        );
+
+        assert_eq!(Location::new(input, input).line, 1);
+        assert_eq!(Location::new(input, "").line, 3);
+    }
+}
@@ -1,3 +1,8 @@
+pub mod answers;
+pub mod error;
+pub mod report;
+pub mod solution;
+pub mod timing;
 pub mod utils;
 
 mod days;
@@ -1,77 +1,637 @@
+use std::{
+    any::Any,
+    fmt,
+    fs::{read_to_string, write},
+    io::ErrorKind,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
 use aoc_2023::{
-    day02,
-    utils::{read_input, Part}, day01, day03, day04, day05, day06, day07, day08, day10, day09, day13, day12, day11, day15, day14,
+    answers::{Answers, Verdict},
+    error::AocError,
+    find_day,
+    report::{read_estimates, render_markdown},
+    solution::Day,
+    timing::{measure, Measurement, Stats},
+    utils::{input_folder, input_path, parse_day_range, read_input_from, Part},
+    DAYS,
 };
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
 
 /// Simple runner for aoc 2023 days
 #[derive(Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// The day that should be run (1 - 25)
-    #[arg(short, long)]
-    day: u8,
+    #[arg(
+        short,
+        long,
+        required_unless_present = "all",
+        value_parser = clap::value_parser!(u8).range(1..=25)
+    )]
+    day: Option<u8>,
 
     /// Part that should be run (1,2)
-    #[arg(short, long)]
+    #[arg(
+        short,
+        long,
+        required_unless_present = "all",
+        value_parser = clap::value_parser!(u8).range(1..=2)
+    )]
+    part: Option<u8>,
+
+    /// Run all implemented days and parts and print a summary table
+    #[arg(short, long, conflicts_with_all = ["day", "part"])]
+    all: bool,
+
+    /// Restricts --all to a range of days (e.g. 3..=12, 3..13 or 5)
+    #[arg(long, requires = "all", value_parser = parse_day_range)]
+    days: Option<RangeInclusive<u8>>,
+
+    /// Compares the computed answers with the stored answers file
+    #[arg(long)]
+    check: bool,
+
+    /// Writes the computed answers into the stored answers file
+    #[arg(long)]
+    record: bool,
+
+    /// Folder containing the dayXX_YY.txt input files [default: $AOC_INPUT_DIR or ./input]
+    #[arg(long)]
+    input_dir: Option<PathBuf>,
+
+    /// Input file for the selected day, `-` reads the input from stdin
+    #[arg(long, conflicts_with = "all")]
+    input_file: Option<PathBuf>,
+
+    /// Runs dayXX_example.txt, or the example of the puzzle description if there is no such file
+    #[arg(long, conflicts_with_all = ["input_file", "check", "record"])]
+    example: bool,
+
+    /// Measures parsing and solving separately and reports min, median and mean
+    #[arg(long)]
+    time: bool,
+
+    /// Number of runs per part used for --time
+    #[arg(
+        long,
+        default_value_t = 1,
+        requires = "time",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    repeat: u32,
+
+    /// Output format of the results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Regenerates the benchmark table from the results of `cargo bench`
+    Report {
+        /// Folder containing the Criterion results
+        #[arg(long, default_value = "target/criterion")]
+        criterion_dir: PathBuf,
+
+        /// Markdown file that contains the previous results and is overwritten
+        #[arg(long, default_value = "Benchmark.md")]
+        output: PathBuf,
+
+        /// Prints the new table instead of writing it
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Args {
+    fn input_dir(&self) -> PathBuf {
+        self.input_dir.clone().unwrap_or_else(input_folder)
+    }
+
+    /// Both parts of a day share the input of part 1 unless an input file is given
+    fn input_part(&self) -> Part {
+        match &self.input_file {
+            Some(input_file) => Part::File(input_file.clone()),
+            None => Part::Part1,
+        }
+    }
+}
+
+/// Outcome of running a single part of a day
+struct Record {
+    day: u8,
     part: u8,
+    input: PathBuf,
+    result: Result<Measurement, Failure>,
+    verdict: Option<Verdict>,
+    /// Answer of the example, only known with --example
+    expected: Option<&'static str>,
 }
 
-fn main() {
+/// Reasons why a single part could not produce an answer
+enum Failure {
+    Input(AocError),
+    Panicked(u8, u8, String),
+    /// Neither an example file nor an embedded example exists, not counted as a failure
+    NoExample(u8, u8),
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
-    run_day(args.day, args.part);
+
+    if let Some(Command::Report {
+        criterion_dir,
+        output,
+        dry_run,
+    }) = &args.command
+    {
+        return report(criterion_dir, output, *dry_run);
+    }
+    let input_dir = args.input_dir();
+
+    let mut answers = if args.check || args.record {
+        match Answers::load(&input_dir) {
+            Ok(answers) => answers,
+            Err(error) => {
+                eprintln!(
+                    "unable to read {}: {}",
+                    Answers::path(&input_dir).display(),
+                    error
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Answers::default()
+    };
+
+    let parts: Vec<(&dyn Day, u8)> = if args.all {
+        let days = args.days.clone().unwrap_or(1..=25);
+
+        DAYS.iter()
+            .filter(|solution| days.contains(&solution.day()))
+            .flat_map(|solution| (1..=solution.parts()).map(|part| (*solution, part)))
+            .collect()
+    } else {
+        let (day, part) = (args.day.unwrap(), args.part.unwrap());
+        let Some(solution) = find_day(day) else {
+            eprintln!("Day {} is not implemented!", day);
+            return ExitCode::FAILURE;
+        };
+        if part > solution.parts() {
+            eprintln!("Day {} has no part {}!", day, part);
+            return ExitCode::FAILURE;
+        }
+
+        vec![(solution, part)]
+    };
+
+    let records: Vec<Record> = parts
+        .into_iter()
+        .map(|(solution, part)| run_part(&args, &mut answers, solution, part))
+        .collect();
+
+    match args.format {
+        Format::Text if args.all => print_table(&records, args.check || args.example, args.time),
+        Format::Text => print_lines(&records, args.time),
+        Format::Json => print_json(&records),
+        Format::Csv => print_csv(&records),
+    }
+
+    if args.record {
+        if let Err(error) = answers.save(&input_dir) {
+            eprintln!(
+                "unable to write {}: {}",
+                Answers::path(&input_dir).display(),
+                error
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if records.iter().any(Record::is_failure) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn report(criterion_dir: &Path, output: &Path, dry_run: bool) -> ExitCode {
+    let estimates = match read_estimates(criterion_dir) {
+        Ok(estimates) if !estimates.is_empty() => estimates,
+        Ok(_) => {
+            eprintln!(
+                "no day benchmarks found in {}, run `cargo bench` first",
+                criterion_dir.display()
+            );
+            return ExitCode::FAILURE;
+        }
+        Err(error) => {
+            eprintln!("unable to read {}: {}", criterion_dir.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let existing = read_to_string(output).unwrap_or_default();
+    let markdown = render_markdown(&existing, &estimates);
+
+    if dry_run {
+        print!("{}", markdown);
+    } else if let Err(error) = write(output, markdown) {
+        eprintln!("unable to write {}: {}", output.display(), error);
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
 }
 
-fn run_day(day: u8, part: u8) {
-    let separate_input_files = vec![];
+/// Runs a single part, a panic is reported as a failure of this part only
+fn run_part(args: &Args, answers: &mut Answers, solution: &dyn Day, part: u8) -> Record {
+    let day = solution.day();
+    let (input, content, expected) = if args.example {
+        read_example(&args.input_dir(), solution, part)
+    } else {
+        let input_part = args.input_part();
+        let input = input_path(&args.input_dir(), day, &input_part);
 
-    let input = read_input(
+        (
+            input,
+            read_input_from(&args.input_dir(), day, input_part).map_err(Failure::Input),
+            None,
+        )
+    };
+
+    let result = content.and_then(|input| {
+        let runs = if args.time { args.repeat } else { 1 };
+
+        match panic::catch_unwind(AssertUnwindSafe(|| measure(solution, &input, part, runs))) {
+            Ok(result) => result.map_err(Failure::Input),
+            Err(payload) => Err(Failure::Panicked(day, part, panic_message(&*payload))),
+        }
+    });
+
+    let verdict = match &result {
+        Ok(measurement) => {
+            if args.record {
+                answers.insert(day, part, measurement.answer.clone());
+            }
+
+            if args.example {
+                Some(match expected {
+                    Some(expected) if expected == measurement.answer => Verdict::Pass,
+                    Some(expected) => Verdict::Fail(expected.to_owned()),
+                    None => Verdict::Missing,
+                })
+            } else {
+                args.check
+                    .then(|| answers.check(day, part, &measurement.answer))
+            }
+        }
+        Err(_) => None,
+    };
+
+    Record {
         day,
-        if separate_input_files.contains(&day) {
-            match part {
-                1 => Part::Part1,
-                2 => Part::Part2,
-                _ => panic!("Unknown part number!"),
+        part,
+        input,
+        result,
+        verdict,
+        expected,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Reads dayXX_example.txt and falls back to the first embedded example of the part.
+/// The expected answer is only known if the file matches one of the embedded examples.
+fn read_example(
+    folder: &Path,
+    solution: &dyn Day,
+    part: u8,
+) -> (PathBuf, Result<String, Failure>, Option<&'static str>) {
+    let day = solution.day();
+    let path = input_path(folder, day, &Part::Example);
+    let mut examples = solution
+        .examples()
+        .iter()
+        .filter(|example| example.part == part);
+
+    match read_input_from(folder, day, Part::Example) {
+        Ok(input) => {
+            let expected = examples
+                .find(|example| example.input.trim() == input.trim())
+                .map(|example| example.answer);
+
+            (path, Ok(input), expected)
+        }
+        Err(AocError::Io { error, .. }) if error.kind() == ErrorKind::NotFound => {
+            match examples.next() {
+                Some(example) => (
+                    PathBuf::from("<embedded example>"),
+                    Ok(example.input.to_owned()),
+                    Some(example.answer),
+                ),
+                None => (path, Err(Failure::NoExample(day, part)), None),
+            }
+        }
+        Err(error) => (path, Err(Failure::Input(error)), None),
+    }
+}
+
+fn print_lines(records: &[Record], time: bool) {
+    for record in records {
+        match (&record.result, &record.verdict, record.expected) {
+            (Ok(measurement), Some(_), Some(expected)) => println!(
+                "Day {:0>2} Part {}: {} (expected {}) {}",
+                record.day,
+                record.part,
+                measurement.answer,
+                expected,
+                record.status().unwrap_or_default()
+            ),
+            (Ok(measurement), Some(verdict), _) => println!(
+                "Day {:0>2} Part {}: {} {}",
+                record.day, record.part, measurement.answer, verdict
+            ),
+            (Ok(measurement), None, _) => {
+                println!(
+                    "Day {:0>2} Part {}: {}",
+                    record.day, record.part, measurement.answer
+                )
+            }
+            (Err(failure), _, _) if failure.is_skipped() => println!("{}", failure),
+            (Err(failure), _, _) => eprintln!("{}", failure),
+        }
+
+        if let (true, Ok(measurement)) = (time, &record.result) {
+            println!(
+                "  parse: {} ({} runs)",
+                format_stats(&measurement.parse),
+                measurement.runs
+            );
+            println!(
+                "  solve: {} ({} runs)",
+                format_stats(&measurement.solve),
+                measurement.runs
+            );
+        }
+    }
+}
+
+fn print_table(records: &[Record], check: bool, time: bool) {
+    let (mut parse_total, mut solve_total) = (Duration::ZERO, Duration::ZERO);
+
+    let mut header = format!("| Day | Part | {:<20} |", "Answer");
+    let mut separator = format!("|----:|-----:|:{:-<20}-|", "");
+    if time {
+        for column in [
+            "Parse min",
+            "Parse median",
+            "Parse mean",
+            "Solve min",
+            "Solve median",
+            "Solve mean",
+        ] {
+            header.push_str(&format!(" {:>12} |", column));
+            separator.push_str(&format!("-{:->12}:|", ""));
+        }
+    } else {
+        header.push_str(&format!(" {:>12} |", "Time"));
+        separator.push_str(&format!("-{:->12}:|", ""));
+    }
+    if check {
+        header.push_str(" Status");
+        separator.push_str(":-------");
+    }
+
+    println!("{}", header);
+    println!("{}", separator);
+
+    for record in records {
+        print!("| {:>3} | {:>4} |", record.day, record.part);
+
+        match &record.result {
+            Ok(measurement) => {
+                let elapsed = measurement.parse.median + measurement.solve.median;
+                parse_total += measurement.parse.median;
+                solve_total += measurement.solve.median;
+
+                print!(" {:<20} |", measurement.answer);
+                if time {
+                    for stats in [&measurement.parse, &measurement.solve] {
+                        print!(
+                            " {:>12} | {:>12} | {:>12} |",
+                            format!("{:.2?}", stats.min),
+                            format!("{:.2?}", stats.median),
+                            format!("{:.2?}", stats.mean)
+                        );
+                    }
+                } else {
+                    print!(" {:>12} |", format!("{:.2?}", elapsed));
+                }
             }
-        } else {
-            Part::Part1
-        },
+            Err(failure) => {
+                if failure.is_skipped() {
+                    print!(" {:<20} |", failure.summary());
+                } else {
+                    print!(" {:<20} |", format!("FAILED: {}", failure.summary()));
+                }
+                for _ in 0..if time { 6 } else { 1 } {
+                    print!(" {:>12} |", "-");
+                }
+            }
+        }
+
+        match &record.verdict {
+            Some(verdict) => println!(" {}", verdict),
+            None => println!(),
+        }
+    }
+
+    // Medians add up to the total, min and mean columns stay empty
+    print!("| {:>3} | {:>4} | {:<20} |", "", "", "Total");
+    if time {
+        for total in [parse_total, solve_total] {
+            print!(
+                " {:>12} | {:>12} | {:>12} |",
+                "",
+                format!("{:.2?}", total),
+                ""
+            );
+        }
+    } else {
+        print!(" {:>12} |", format!("{:.2?}", parse_total + solve_total));
+    }
+    println!();
+
+    for failure in records
+        .iter()
+        .filter_map(|record| record.result.as_ref().err())
+        .filter(|failure| !failure.is_skipped())
+    {
+        eprintln!("\n{}", failure);
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "min {:.2?}, median {:.2?}, mean {:.2?}",
+        stats.min, stats.median, stats.mean
     )
-    .expect("unable to read input file");
-
-    print!("Day {:0>2} Part {}: ", day, part);
-    
-    match (day, part) {
-        (1, 1) => println!("{}", day01::solve1(day01::parse_input(&input))),
-        (1, 2) => println!("{}", day01::solve1(day01::parse_input(&input))),
-        (2, 1) => println!("{}", day02::solve1(day02::parse_input(&mut input.as_str()).unwrap())),
-        (2, 2) => println!("{}", day02::solve2(day02::parse_input(&mut input.as_str()).unwrap())),
-        (3, 1) => println!("{}", day03::solve1(day03::parse_input(&input))),
-        (3, 2) => println!("{}", day03::solve2(day03::parse_input(&input))),
-        (4, 1) => println!("{}", day04::solve1(day04::parse_input(&mut input.as_str()).unwrap())),
-        (4, 2) => println!("{}", day04::solve2(day04::parse_input(&mut input.as_str()).unwrap())),
-        (5, 1) => println!("{}", day05::solve1(day05::parse_input(&mut input.as_str()).unwrap())),
-        (5, 2) => println!("{}", day05::solve2(day05::parse_input(&mut input.as_str()).unwrap())),
-        (6, 1) => println!("{}", day06::solve1(day06::parse_input(&mut input.as_str()).unwrap())),
-        (6, 2) => println!("{}", day06::solve2(day06::parse_input(&mut input.as_str()).unwrap())),
-        (7, 1) => println!("{}", day07::solve1(day07::parse_input(&mut input.as_str()).unwrap())),
-        (7, 2) => println!("{}", day07::solve2(day07::parse_input(&mut input.as_str()).unwrap())),
-        (8, 1) => println!("{}", day08::solve1(day08::parse_input(&mut input.as_str()).unwrap())),
-        (8, 2) => println!("{}", day08::solve2(day08::parse_input(&mut input.as_str()).unwrap())),
-        (9, 1) => println!("{}", day09::solve1(day09::parse_input(&mut input.as_str()).unwrap())),
-        (9, 2) => println!("{}", day09::solve2(day09::parse_input(&mut input.as_str()).unwrap())),
-        (10, 1) => println!("{}", day10::solve1(day10::parse_input(&input))),
-        (10, 2) => println!("{}", day10::solve2(day10::parse_input(&input))),
-        (11, 1) => println!("{}", day11::solve1(day11::parse_input(&input))),
-        (11, 2) => println!("{}", day11::solve2(day11::parse_input(&input))),
-        (12, 1) => println!("{}", day12::solve1(day12::parse_input(&mut input.as_str()).unwrap())),
-        (12, 2) => println!("{}", day12::solve2(day12::parse_input(&mut input.as_str()).unwrap())),
-        (13, 1) => println!("{}", day13::solve1(day13::parse_input(&mut input.as_str()).unwrap())),
-        (13, 2) => println!("{}", day13::solve2(day13::parse_input(&mut input.as_str()).unwrap())),
-        (14, 1) => println!("{}", day14::solve1(day14::parse_input(&mut input.as_str()).unwrap())),
-        (14, 2) => println!("{}", day14::solve2(day14::parse_input(&mut input.as_str()).unwrap())),
-        (15, 1) => println!("{}", day15::solve1(day15::parse_input(&input))),
-        (15, 2) => println!("{}", day15::solve2(day15::parse_input(&input))),
-        _ => todo!(),
+}
+
+fn print_json(records: &[Record]) {
+    println!("[");
+
+    for (index, record) in records.iter().enumerate() {
+        let (answer, parse_time, solve_time, error) = match &record.result {
+            Ok(measurement) => (
+                Some(measurement.answer.clone()),
+                Some(measurement.parse.median.as_nanos() as u64),
+                Some(measurement.solve.median.as_nanos() as u64),
+                None,
+            ),
+            Err(failure) => (None, None, None, Some(failure.to_string())),
+        };
+
+        let row = json!({
+            "day": record.day,
+            "part": record.part,
+            "answer": answer,
+            "parse_time_ns": parse_time,
+            "solve_time_ns": solve_time,
+            "input": record.input.to_string_lossy(),
+            "status": record.status(),
+            "error": error,
+        });
+
+        println!(
+            "  {}{}",
+            row,
+            if index + 1 < records.len() { "," } else { "" }
+        );
+    }
+
+    println!("]");
+}
+
+fn print_csv(records: &[Record]) {
+    println!("day,part,answer,parse_time_ns,solve_time_ns,input,status,error");
+
+    for record in records {
+        let (answer, parse_time, solve_time, error) = match &record.result {
+            Ok(measurement) => (
+                measurement.answer.clone(),
+                measurement.parse.median.as_nanos().to_string(),
+                measurement.solve.median.as_nanos().to_string(),
+                String::new(),
+            ),
+            Err(failure) => (
+                String::new(),
+                String::new(),
+                String::new(),
+                failure.to_string(),
+            ),
+        };
+
+        println!(
+            "{},{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(&answer),
+            parse_time,
+            solve_time,
+            csv_field(&record.input.to_string_lossy()),
+            record.status().unwrap_or(""),
+            csv_field(&error)
+        );
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+impl Record {
+    fn is_failure(&self) -> bool {
+        match &self.result {
+            Ok(_) => matches!(self.verdict, Some(Verdict::Fail(_))),
+            Err(failure) => !failure.is_skipped(),
+        }
+    }
+
+    fn status(&self) -> Option<&'static str> {
+        match &self.result {
+            Err(failure) if failure.is_skipped() => return Some("SKIPPED"),
+            Err(_) => return Some("ERROR"),
+            Ok(_) => {}
+        }
+
+        match self.verdict {
+            Some(Verdict::Pass) => Some("PASS"),
+            Some(Verdict::Fail(_)) => Some("FAIL"),
+            Some(Verdict::Missing) => Some("MISSING"),
+            None => None,
+        }
+    }
+}
+
+impl Failure {
+    /// Short description that fits into the summary table
+    fn summary(&self) -> &'static str {
+        match self {
+            Failure::Input(AocError::Io { .. }) => "no input",
+            Failure::Input(AocError::Solve { .. }) => "no answer",
+            Failure::Input(AocError::UnknownPart { .. }) => "no such part",
+            Failure::Input(_) => "parse error",
+            Failure::Panicked(..) => "panicked",
+            Failure::NoExample(..) => "no example",
+        }
+    }
+
+    fn is_skipped(&self) -> bool {
+        matches!(self, Failure::NoExample(..))
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Input(error) => error.fmt(f),
+            Failure::Panicked(day, part, message) => {
+                write!(f, "Day {:0>2} Part {} panicked: {}", day, part, message)
+            }
+            Failure::NoExample(day, part) => {
+                write!(f, "Day {:0>2} Part {} has no example", day, part)
+            }
+        }
     }
 }
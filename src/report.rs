@@ -0,0 +1,310 @@
+use std::{collections::BTreeMap, fmt::Write, fs::read_to_string, io, path::Path};
+
+use serde_json::Value;
+
+/// What a single Criterion benchmark of a day measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Parse,
+    Part1,
+    Part2,
+}
+
+/// Point estimates of a Criterion benchmark, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate {
+    pub mean: f64,
+    pub std_dev: f64,
+}
+
+pub type Estimates = BTreeMap<(u8, Kind), Estimate>;
+
+/// Collects all `new/estimates.json` files below the Criterion output folder
+/// (usually `target/criterion`) that belong to a day benchmark.
+pub fn read_estimates(criterion_dir: &Path) -> io::Result<Estimates> {
+    let mut estimates = Estimates::new();
+    let mut folders = vec![criterion_dir.to_path_buf()];
+
+    while let Some(folder) = folders.pop() {
+        for entry in folder.read_dir()? {
+            let path = entry?.path();
+            if !path.is_dir() {
+                continue;
+            }
+
+            if path.file_name().is_some_and(|name| name == "new") {
+                if let Some((key, estimate)) = read_benchmark(&path)? {
+                    estimates.insert(key, estimate);
+                }
+            } else if path.file_name().is_some_and(|name| name != "report") {
+                folders.push(path);
+            }
+        }
+    }
+
+    Ok(estimates)
+}
+
+fn read_benchmark(folder: &Path) -> io::Result<Option<((u8, Kind), Estimate)>> {
+    let benchmark = read_json(&folder.join("benchmark.json"))?;
+    let estimates = read_json(&folder.join("estimates.json"))?;
+
+    let Some(key) = benchmark["full_id"].as_str().and_then(benchmark_key) else {
+        return Ok(None);
+    };
+
+    let mean = estimates["mean"]["point_estimate"].as_f64();
+    let std_dev = estimates["std_dev"]["point_estimate"].as_f64();
+
+    match (mean, std_dev) {
+        (Some(mean), Some(std_dev)) => Ok(Some((key, Estimate { mean, std_dev }))),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has no mean or std dev", folder.display()),
+        )),
+    }
+}
+
+fn read_json(path: &Path) -> io::Result<Value> {
+    serde_json::from_str(&read_to_string(path)?).map_err(io::Error::from)
+}
+
+/// Maps benchmark ids like `Day01 Part1`, `Day01/part1` or `Day01 input parsing` to their key
+pub fn benchmark_key(id: &str) -> Option<(u8, Kind)> {
+    let id = id.to_ascii_lowercase().replace([' ', '/', '_'], "");
+    let rest = &id[id.find("day")? + 3..];
+
+    let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+    let day = rest[..digits].parse().ok()?;
+    let rest = &rest[digits..];
+
+    let kind = if rest.contains("pars") {
+        Kind::Parse
+    } else if rest.contains("part1") {
+        Kind::Part1
+    } else if rest.contains("part2") {
+        Kind::Part2
+    } else {
+        return None;
+    };
+
+    Some((day, kind))
+}
+
+/// Reads the mean of every `DayXX Part Y` row of an existing benchmark table
+pub fn parse_table(markdown: &str) -> BTreeMap<(u8, Kind), f64> {
+    markdown
+        .lines()
+        .filter(|line| line.starts_with('|'))
+        .filter_map(|line| {
+            let mut cells = line.split('|').skip(1).map(str::trim);
+            let key = benchmark_key(cells.next()?)?;
+            let mean = parse_duration(cells.next()?)?;
+
+            Some((key, mean))
+        })
+        .collect()
+}
+
+/// Parses durations like `28.556 µs` into nanoseconds
+pub fn parse_duration(value: &str) -> Option<f64> {
+    let (number, unit) = value.trim().split_once(' ')?;
+    let number: f64 = number.parse().ok()?;
+
+    let factor = match unit.trim() {
+        "ps" => 1e-3,
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    Some(number * factor)
+}
+
+/// Formats nanoseconds the same way Criterion does (four significant digits)
+pub fn format_duration(ns: f64) -> String {
+    let (value, unit) = if ns < 1.0 {
+        (ns * 1e3, "ps")
+    } else if ns < 1e3 {
+        (ns, "ns")
+    } else if ns < 1e6 {
+        (ns / 1e3, "µs")
+    } else if ns < 1e9 {
+        (ns / 1e6, "ms")
+    } else {
+        (ns / 1e9, "s")
+    };
+
+    let value = if value < 10.0 {
+        format!("{:.4}", value)
+    } else if value < 100.0 {
+        format!("{:.3}", value)
+    } else {
+        format!("{:.2}", value)
+    };
+
+    format!("{} {}", value, unit)
+}
+
+/// Renders the benchmark table, `previous` holds the means of the last committed table.
+pub fn render_table(estimates: &Estimates, previous: &BTreeMap<(u8, Kind), f64>) -> String {
+    let mut table = String::new();
+
+    let _ = writeln!(
+        table,
+        "| Benchmark         | Mean         |     |   std dev  | Parse        | Change   |"
+    );
+    let _ = writeln!(
+        table,
+        "|:------------------|-------------:|:---:|-----------:|-------------:|---------:|"
+    );
+
+    for (&(day, kind), estimate) in estimates.iter() {
+        let part = match kind {
+            Kind::Parse => continue,
+            Kind::Part1 => 1,
+            Kind::Part2 => 2,
+        };
+
+        let parse = estimates
+            .get(&(day, Kind::Parse))
+            .map_or(String::from("-"), |parse| format_duration(parse.mean));
+
+        let change = previous
+            .get(&(day, kind))
+            .map_or(String::from("new"), |mean| {
+                format!("{:+.2}%", (estimate.mean - mean) / mean * 100.0)
+            });
+
+        let _ = writeln!(
+            table,
+            "| {:<17} | {:<12} |  ±  | {:<10} | {:<12} | {:>8} |",
+            format!("Day{:0>2} Part {}", day, part),
+            format_duration(estimate.mean),
+            format_duration(estimate.std_dev),
+            parse,
+            change
+        );
+    }
+
+    table
+}
+
+/// Replaces the table of an existing `Benchmark.md` and keeps the text around it
+pub fn render_markdown(existing: &str, estimates: &Estimates) -> String {
+    let previous = parse_table(existing);
+    let lines: Vec<&str> = existing.lines().collect();
+
+    let table_start = lines
+        .iter()
+        .position(|line| line.starts_with('|'))
+        .unwrap_or(lines.len());
+    let table_end = table_start
+        + lines[table_start..]
+            .iter()
+            .take_while(|line| line.starts_with('|'))
+            .count();
+
+    let mut markdown = String::new();
+    for line in &lines[..table_start] {
+        let _ = writeln!(markdown, "{}", line);
+    }
+
+    markdown.push_str(&render_table(estimates, &previous));
+
+    for line in &lines[table_end..] {
+        let _ = writeln!(markdown, "{}", line);
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{
+        benchmark_key, format_duration, parse_duration, parse_table, render_markdown, Estimate,
+        Estimates, Kind,
+    };
+
+    const BENCHMARK_MD: &str = r#"Benchmark results
+=================
+
+| Benchmark         | Mean         |     |   std dev  |
+|:------------------|-------------:|:---:|-----------:|
+| Day01 Part 1      | 28.556 µs	   |  ±  | 1.0670 µs  |
+| Day06 Part 1      | 292.76 ns	   |  ±  | 974.84 ps  |
+"#;
+
+    #[test]
+    fn keys() {
+        assert_eq!(benchmark_key("Day01 Part1"), Some((1, Kind::Part1)));
+        assert_eq!(
+            benchmark_key("Day17 input parsing"),
+            Some((17, Kind::Parse))
+        );
+        assert_eq!(benchmark_key("Day04/part2"), Some((4, Kind::Part2)));
+        assert_eq!(benchmark_key("Day06 Part 1"), Some((6, Kind::Part1)));
+        assert_eq!(benchmark_key("Something else"), None);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("28.556 µs"), Some(28556.0));
+        assert_eq!(parse_duration("5.0062 ms"), Some(5006200.0));
+        assert_eq!(format_duration(28556.0), "28.556 µs");
+        assert_eq!(format_duration(292.76), "292.76 ns");
+        assert_eq!(format_duration(0.97484), "974.84 ps");
+        assert_eq!(format_duration(5006200.0), "5.0062 ms");
+    }
+
+    #[test]
+    fn markdown() {
+        let previous = parse_table(BENCHMARK_MD);
+        assert_eq!(previous.len(), 2);
+
+        let mut estimates = Estimates::new();
+        estimates.insert(
+            (1, Kind::Parse),
+            Estimate {
+                mean: 1000.0,
+                std_dev: 10.0,
+            },
+        );
+        estimates.insert(
+            (1, Kind::Part1),
+            Estimate {
+                mean: 14278.0,
+                std_dev: 500.0,
+            },
+        );
+        estimates.insert(
+            (1, Kind::Part2),
+            Estimate {
+                mean: 30000.0,
+                std_dev: 600.0,
+            },
+        );
+
+        let markdown = render_markdown(BENCHMARK_MD, &estimates);
+
+        assert!(markdown.starts_with("Benchmark results\n=================\n\n| Benchmark"));
+        assert!(markdown.contains(
+            "| Day01 Part 1      | 14.278 µs    |  ±  | 500.00 ns  | 1.0000 µs    |  -50.00% |"
+        ));
+        assert!(markdown.contains(
+            "| Day01 Part 2      | 30.000 µs    |  ±  | 600.00 ns  | 1.0000 µs    |      new |"
+        ));
+        assert_eq!(parse_table(&markdown).len(), 2);
+    }
+
+    #[test]
+    fn text_after_table() {
+        let existing = format!("{}\nMeasured on a laptop.\n\n## Notes\n", BENCHMARK_MD);
+        let markdown = render_markdown(&existing, &Estimates::new());
+
+        assert!(markdown.starts_with("Benchmark results\n"));
+        assert!(markdown.ends_with("|\n\nMeasured on a laptop.\n\n## Notes\n"));
+        assert!(!markdown.contains("Day06"));
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::error::AocError;
+
+/// A single Advent of Code day, split into the parsing step and both puzzle parts.
+pub trait Solution {
+    /// The calendar day (1 - 25) this solution belongs to
+    const DAY: u8;
+
+    /// The examples of the puzzle description, used by the tests and `--example`
+    const EXAMPLES: &'static [Example];
+
+    /// Number of puzzle parts, the last day only has a single one
+    const PARTS: u8 = 2;
+
+    type Input<'s>;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    /// Fails with [`AocError::Solve`] if the input parsed but has no answer
+    fn part1(input: Self::Input<'_>) -> Result<Self::Output, AocError>;
+
+    fn part2(input: Self::Input<'_>) -> Result<Self::Output, AocError>;
+}
+
+/// Object safe view on a [`Solution`], used by the registry in [`crate::DAYS`].
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+
+    fn examples(&self) -> &'static [Example];
+
+    /// Number of puzzle parts, parts are numbered from 1
+    fn parts(&self) -> u8;
+
+    /// Only parses the input and discards the result
+    fn parse(&self, input: &str) -> Result<(), AocError>;
+
+    /// Parses the input and solves the given part (1, 2), fails with [`AocError::UnknownPart`]
+    /// on a part the day does not have
+    fn solve(&self, input: &str, part: u8) -> Result<String, AocError>;
+
+    /// Same as [`Day::solve`], but measures parsing and solving separately
+    fn solve_timed(&self, input: &str, part: u8) -> Result<Timed, AocError>;
+}
+
+/// Example input of a part together with its expected answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    pub answer: &'static str,
+}
+
+impl Example {
+    pub const fn new(part: u8, input: &'static str, answer: &'static str) -> Example {
+        Example {
+            part,
+            input,
+            answer,
+        }
+    }
+}
+
+/// Answer of a single part together with the time spent in each phase.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+impl<S: Solution + Sync> Day for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<(), AocError> {
+        S::parse(input).map(|_| ())
+    }
+
+    fn solve(&self, input: &str, part: u8) -> Result<String, AocError> {
+        check_part::<S>(part)?;
+        let input = S::parse(input)?;
+
+        Ok(match part {
+            1 => S::part1(input)?.to_string(),
+            _ => S::part2(input)?.to_string(),
+        })
+    }
+
+    fn solve_timed(&self, input: &str, part: u8) -> Result<Timed, AocError> {
+        check_part::<S>(part)?;
+
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let output = match part {
+            1 => S::part1(input)?,
+            _ => S::part2(input)?,
+        };
+        let solve_time = start.elapsed();
+
+        Ok(Timed {
+            answer: output.to_string(),
+            parse_time,
+            solve_time,
+        })
+    }
+}
+
+fn check_part<S: Solution>(part: u8) -> Result<(), AocError> {
+    if (1..=S::PARTS).contains(&part) {
+        Ok(())
+    } else {
+        Err(AocError::UnknownPart { day: S::DAY, part })
+    }
+}
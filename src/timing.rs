@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use crate::{error::AocError, solution::Day};
+
+/// Summary of repeated time measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+/// Answer of a part and the timing of both phases over all runs.
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub answer: String,
+    pub runs: u32,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples
+    pub fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+        })
+    }
+}
+
+/// Solves the part `runs` times, the answer is taken from the first run.
+pub fn measure(
+    solution: &dyn Day,
+    input: &str,
+    part: u8,
+    runs: u32,
+) -> Result<Measurement, AocError> {
+    let runs = runs.max(1);
+    let first = solution.solve_timed(input, part)?;
+
+    let mut parse_times = Vec::with_capacity(runs as usize);
+    let mut solve_times = Vec::with_capacity(runs as usize);
+    parse_times.push(first.parse_time);
+    solve_times.push(first.solve_time);
+
+    for _ in 1..runs {
+        let timed = solution.solve_timed(input, part)?;
+        parse_times.push(timed.parse_time);
+        solve_times.push(timed.solve_time);
+    }
+
+    Ok(Measurement {
+        answer: first.answer,
+        runs,
+        parse: Stats::new(parse_times).unwrap(),
+        solve: Stats::new(solve_times).unwrap(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timing::Stats;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+
+        assert_eq!(Stats::new(vec![]), None);
+        assert_eq!(
+            Stats::new(vec![ms(5), ms(1), ms(3)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: ms(3),
+            })
+        );
+        assert_eq!(
+            Stats::new(vec![ms(4), ms(1), ms(2), ms(9)]),
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: ms(4),
+            })
+        );
+    }
+}
@@ -1,52 +1,176 @@
-use std::{fs::read_to_string, io};
+pub mod geom;
+pub mod grid;
+pub mod interval;
+
+use std::{
+    env,
+    fs::read_to_string,
+    io::{self, Read},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
 
 use winnow::{
-    ascii::{digit1, multispace0, dec_int},
+    ascii::{dec_int, digit1, multispace0},
     combinator::preceded,
+    error::{ContextError, ErrMode},
     PResult, Parser,
 };
 
-const INPUT_FOLDER: &'static str = "./input";
+use crate::error::{AocError, Location};
+
+const INPUT_FOLDER: &str = "./input";
+const INPUT_FOLDER_VARIABLE: &str = "AOC_INPUT_DIR";
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum Part {
     Example,
     Part1,
     Part2,
+    /// Explicit input file instead of the `dayXX_YY.txt` naming, `-` reads from stdin
+    File(PathBuf),
 }
 
 impl Part {
-    fn extension(self) -> &'static str {
+    fn extension(&self) -> &'static str {
         match self {
             Part::Example => "example",
             Part::Part1 => "01",
             Part::Part2 => "02",
+            Part::File(_) => unreachable!("input files have no extension"),
         }
     }
 }
 
-pub fn read_input(day: u8, part: Part) -> io::Result<String> {
-    read_to_string(format!(
-        "{}/day{:0>2}_{}.txt",
-        INPUT_FOLDER,
-        day,
-        part.extension()
-    ))
+/// The folder containing the input files, `AOC_INPUT_DIR` takes precedence over `./input`
+pub fn input_folder() -> PathBuf {
+    env::var_os(INPUT_FOLDER_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(INPUT_FOLDER))
 }
 
-pub fn parse_u32<'s>(input: &mut &'s str) -> PResult<u32> {
-    digit1(input).map(|digits| str::parse(digits).unwrap())
+pub fn input_path(folder: &Path, day: u8, part: &Part) -> PathBuf {
+    match part {
+        Part::File(path) => path.clone(),
+        part => folder.join(format!("day{:0>2}_{}.txt", day, part.extension())),
+    }
+}
+
+pub fn read_input(day: u8, part: Part) -> Result<String, AocError> {
+    read_input_from(&input_folder(), day, part)
+}
+
+pub fn read_input_from(folder: &Path, day: u8, part: Part) -> Result<String, AocError> {
+    let path = input_path(folder, day, &part);
+
+    let result = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        read_to_string(&path)
+    };
+
+    result.map_err(|error| AocError::Io { path, error })
 }
 
-pub fn parse_u64<'s>(input: &mut &'s str) -> PResult<u64> {
-    digit1(input).map(|digits| str::parse(digits).unwrap())
+/// Parses a range of days like `3..=12`, `3..13` or `5`, all days have to be within `1..=25`
+pub fn parse_day_range(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("'{}' is not a valid day", day))
+    };
+
+    let range = if let Some((start, end)) = value.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = value.split_once("..") {
+        parse_day(start)?..=parse_day(end)?.saturating_sub(1)
+    } else {
+        let day = parse_day(value)?;
+        day..=day
+    };
+
+    let days = 1..=25;
+    if range.is_empty() || !days.contains(range.start()) || !days.contains(range.end()) {
+        return Err(format!("'{}' is not a range of days within 1..=25", value));
+    }
+
+    Ok(range)
 }
 
-pub fn parse_aligned_u32<'s>(input: &mut &'s str) -> PResult<u32> {
-    preceded(multispace0, digit1)
-        .parse_next(input)
-        .map(|digits| str::parse(digits).unwrap())
+/// Runs `parser` on the input and fails if anything except trailing whitespace is left over.
+pub fn parse_complete<'s, O>(
+    day: u8,
+    input: &mut &'s str,
+    mut parser: impl Parser<&'s str, O, ContextError>,
+) -> Result<O, AocError> {
+    let original = *input;
+
+    match parser.parse_next(input) {
+        Ok(output) if input.trim().is_empty() => Ok(output),
+        Ok(_) => Err(AocError::LeftoverInput {
+            day,
+            location: Location::new(original, input),
+        }),
+        Err(error) => {
+            let message = match error {
+                ErrMode::Incomplete(_) => String::new(),
+                ErrMode::Backtrack(error) | ErrMode::Cut(error) => error.to_string(),
+            };
+
+            Err(AocError::Parse {
+                day,
+                location: Location::new(original, input),
+                message: if message.is_empty() {
+                    String::from("invalid input")
+                } else {
+                    message
+                },
+            })
+        }
+    }
+}
+
+/// Fails instead of truncating if the number does not fit into a `u16`
+pub fn parse_u16(input: &mut &str) -> PResult<u16> {
+    digit1.try_map(str::parse).parse_next(input)
+}
+
+pub fn parse_u32(input: &mut &str) -> PResult<u32> {
+    digit1.try_map(str::parse).parse_next(input)
+}
+
+pub fn parse_u64(input: &mut &str) -> PResult<u64> {
+    digit1.try_map(str::parse).parse_next(input)
+}
+
+pub fn parse_aligned_u32(input: &mut &str) -> PResult<u32> {
+    preceded(multispace0, digit1.try_map(str::parse)).parse_next(input)
 }
 
 pub fn parse_i32<'s>(input: &mut &'s str) -> PResult<i32> {
     dec_int(input)
-}
\ No newline at end of file
+}
+
+pub fn parse_i64(input: &mut &str) -> PResult<i64> {
+    dec_int(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::parse_day_range;
+
+    #[test]
+    fn day_ranges() {
+        assert_eq!(parse_day_range("3..=12"), Ok(3..=12));
+        assert_eq!(parse_day_range("3..13"), Ok(3..=12));
+        assert_eq!(parse_day_range(" 5 "), Ok(5..=5));
+        assert_eq!(parse_day_range("1..26"), Ok(1..=25));
+
+        assert!(parse_day_range("30..=40").is_err());
+        assert!(parse_day_range("0..=3").is_err());
+        assert!(parse_day_range("7..=3").is_err());
+        assert!(parse_day_range("5..5").is_err());
+        assert!(parse_day_range("x").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::{CheckedAdd, CheckedSub, One, Zero};
+
+/// Grid direction, the variants are ordered clockwise starting upwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Offset of a single step, rows grow downwards
+    pub fn unit<T>(self) -> Point<T>
+    where
+        T: Zero + One + Neg<Output = T>,
+    {
+        match self {
+            Direction::Up => Point::new(-T::one(), T::zero()),
+            Direction::Right => Point::new(T::zero(), T::one()),
+            Direction::Down => Point::new(T::one(), T::zero()),
+            Direction::Left => Point::new(T::zero(), -T::one()),
+        }
+    }
+
+    /// A distinct bit per direction, for sets of directions in a `u8`
+    pub fn bit_mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Position or offset on a grid as `row` and `col`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point<T> {
+    pub row: T,
+    pub col: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(row: T, col: T) -> Point<T> {
+        Point { row, col }
+    }
+
+    /// Applies `f` to both coordinates, e.g. to convert between coordinate types
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Point<U> {
+        Point::new(f(self.row), f(self.col))
+    }
+}
+
+impl<T: CheckedAdd> Point<T> {
+    pub fn checked_add(&self, other: &Point<T>) -> Option<Point<T>> {
+        Some(Point::new(
+            self.row.checked_add(&other.row)?,
+            self.col.checked_add(&other.col)?,
+        ))
+    }
+}
+
+impl<T: CheckedSub> Point<T> {
+    pub fn checked_sub(&self, other: &Point<T>) -> Option<Point<T>> {
+        Some(Point::new(
+            self.row.checked_sub(&other.row)?,
+            self.col.checked_sub(&other.col)?,
+        ))
+    }
+}
+
+impl Point<usize> {
+    /// The neighbouring point in `direction`, `None` below zero
+    pub fn step(self, direction: Direction) -> Option<Point<usize>> {
+        let offset = direction.unit::<isize>();
+
+        Some(Point::new(
+            self.row.checked_add_signed(offset.row)?,
+            self.col.checked_add_signed(offset.col)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((row, col): (T, T)) -> Point<T> {
+        Point::new(row, col)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> (T, T) {
+        (point.row, point.col)
+    }
+}
+
+/// Twice the area of a simple polygon, the vertices have to be given in order (shoelace formula).
+pub fn double_area(vertices: &[Point<i64>]) -> i64 {
+    let mut sum = 0;
+
+    for (index, a) in vertices.iter().enumerate() {
+        let b = vertices[(index + 1) % vertices.len()];
+        sum += a.row * b.col - a.col * b.row;
+    }
+
+    sum.abs()
+}
+
+/// Number of lattice points strictly inside a polygon with lattice vertices,
+/// `boundary` is the number of lattice points on its border (Pick's theorem).
+pub fn interior_points(vertices: &[Point<i64>], boundary: i64) -> i64 {
+    (double_area(vertices) - boundary + 2) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::geom::{double_area, interior_points, Direction, Point};
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse(), direction.turn_right().turn_right());
+            assert_eq!(
+                direction.unit::<i32>() + direction.reverse().unit(),
+                Point::new(0, 0)
+            );
+        }
+
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.unit::<i64>() * 3, Point::new(0, -3));
+        assert_eq!(
+            Direction::ALL.map(Direction::bit_mask).iter().sum::<u8>(),
+            0b1111
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let point = Point::new(0_usize, 2);
+
+        assert_eq!(point.step(Direction::Up), None);
+        assert_eq!(point.step(Direction::Left), Some(Point::new(0, 1)));
+        assert_eq!(point.checked_sub(&Point::new(1, 0)), None);
+        assert_eq!(point.checked_add(&Point::new(1, 1)), Some(Point::new(1, 3)));
+        assert_eq!(Point::new(u8::MAX, 0).checked_add(&Point::new(1, 0)), None);
+    }
+
+    #[test]
+    fn square() {
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0)].map(Point::from);
+
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(interior_points(&square, 16), 9);
+    }
+}
@@ -0,0 +1,265 @@
+use std::ops::{Index, IndexMut};
+
+use crate::utils::geom::{Direction, Point};
+
+use winnow::{
+    ascii::line_ending,
+    combinator::separated,
+    error::{ErrMode, ErrorKind, ParserError},
+    stream::Offset,
+    token::take_till,
+    PResult, Parser,
+};
+
+const NEIGHBOURS_8: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(-1, 0),
+    Point::new(-1, 1),
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+];
+
+/// Rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `width` is zero or `cells` does not hold exactly `width * height` values
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(width > 0, "grid width must not be zero");
+        assert_eq!(cells.len(), width * height, "grid size does not match");
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Point<usize>) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.row * self.width + position.col])
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.row * self.width + position.col])
+    }
+
+    /// Moves `position` by `offset`, `None` if that leaves the grid
+    pub fn offset(&self, position: Point<usize>, offset: Point<isize>) -> Option<Point<usize>> {
+        let moved = Point::new(
+            position.row.checked_add_signed(offset.row)?,
+            position.col.checked_add_signed(offset.col)?,
+        );
+
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The neighbouring position in `direction`, `None` if that leaves the grid
+    pub fn step(&self, position: Point<usize>, direction: Direction) -> Option<Point<usize>> {
+        self.offset(position, direction.unit())
+    }
+
+    /// Horizontal and vertical neighbours inside the grid
+    pub fn neighbours4(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |&direction| self.step(position, direction))
+    }
+
+    /// Horizontal, vertical and diagonal neighbours inside the grid
+    pub fn neighbours8(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// All positions in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Point::new(row, col)))
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|col| col.cloned()).collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates by a quarter turn, the first column becomes the first row in reverse
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|col| (0..self.height).rev().map(move |row| Point::new(row, col)))
+            .map(|position| self[position].clone())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        assert!(
+            position.col < self.width,
+            "column {} out of bounds",
+            position.col
+        );
+        &self.cells[position.row * self.width + position.col]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        assert!(
+            position.col < self.width,
+            "column {} out of bounds",
+            position.col
+        );
+        &mut self.cells[position.row * self.width + position.col]
+    }
+}
+
+/// Parses lines of equal length into a grid of their bytes, the last newline is not consumed
+pub fn parse_grid(input: &mut &str) -> PResult<Grid<u8>> {
+    let original = *input;
+    let lines: Vec<&str> =
+        separated(1.., take_till(1.., ['\n', '\r']), line_ending).parse_next(input)?;
+
+    let width = lines[0].len();
+    if let Some(line) = lines.iter().find(|line| line.len() != width) {
+        *input = &original[line.offset_from(&original)..];
+        return Err(ErrMode::from_error_kind(input, ErrorKind::Verify));
+    }
+
+    let cells = lines.iter().flat_map(|line| line.bytes()).collect();
+
+    Ok(Grid::new(width, lines.len(), cells))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::{
+        geom::Point,
+        grid::{parse_grid, Grid},
+    };
+
+    #[test]
+    fn parse_and_navigate() {
+        let grid = parse_grid(&mut "abc\ndef\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 0)], b'd');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        assert_eq!(grid.row(1), b"def");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), b"cf");
+
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+
+        assert!(parse_grid(&mut "abc\nde\n").is_err());
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let grid = parse_grid(&mut "abc\r\ndef\r\n").unwrap();
+
+        assert_eq!(grid, parse_grid(&mut "abc\ndef\n").unwrap());
+
+        let mut input = "abc\r\nde\r\nghi\r\n";
+        assert!(parse_grid(&mut input).is_err());
+        assert_eq!(input, "de\r\nghi\r\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "grid width must not be zero")]
+    fn zero_width() {
+        Grid::<u8>::new(0, 3, Vec::new());
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(grid.transpose(), Grid::new(2, 3, vec![1, 4, 2, 5, 3, 6]));
+        assert_eq!(
+            grid.rotate_clockwise(),
+            Grid::new(2, 3, vec![4, 1, 5, 2, 6, 3])
+        );
+        assert_eq!(
+            (0..4).fold(grid.clone(), |grid, _| grid.rotate_clockwise()),
+            grid
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::{Add, Range, Sub};
+
+/// Half open interval `start..end`, empty if `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of values in the interval, must not be called on an empty interval
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+
+    pub fn intersect(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end)).non_empty()
+    }
+
+    /// Splits into the values below `value` and the values from `value` on
+    pub fn split_at(&self, value: T) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        (
+            Interval::new(self.start, self.end.min(value)).non_empty(),
+            Interval::new(self.start.max(value), self.end).non_empty(),
+        )
+    }
+
+    /// Moves the interval such that `from` ends up at `to`
+    pub fn translate(&self, from: T, to: T) -> Interval<T> {
+        Interval::new(self.start - from + to, self.end - from + to)
+    }
+
+    fn non_empty(self) -> Option<Interval<T>> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Interval<T> {
+        Interval {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T> From<Interval<T>> for Range<T> {
+    fn from(interval: Interval<T>) -> Range<T> {
+        interval.start..interval.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::interval::Interval;
+
+    #[test]
+    fn split_and_intersect() {
+        let interval = Interval::new(1_u16, 4001);
+
+        assert_eq!(
+            interval.split_at(1351),
+            (Some(Interval::new(1, 1351)), Some(Interval::new(1351, 4001)))
+        );
+        assert_eq!(interval.split_at(1), (None, Some(interval)));
+        assert_eq!(interval.split_at(5000), (Some(interval), None));
+
+        assert_eq!(
+            interval.intersect(&Interval::new(4000, 5000)),
+            Some(Interval::new(4000, 4001))
+        );
+        assert_eq!(interval.intersect(&Interval::new(4001, 5000)), None);
+        assert_eq!(Interval::new(50_u64, 60).translate(50, 52), Interval::new(52, 62));
+    }
+}